@@ -0,0 +1,213 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::error::column_of;
+use crate::parse;
+use crate::{ AocError, AocResult };
+
+pub const DEFAULT_PATH: &str = "./answers.txt";
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Debug)]
+pub struct AnswerKey {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+}
+
+impl AnswerKey {
+    pub fn new(day: u8, part: u8, input: &str) -> Self {
+        AnswerKey {
+            day,
+            part,
+            input: input.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for AnswerKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "day {:02} part {} ({})", self.day, self.part, self.input)
+    }
+}
+
+#[derive(Default)]
+pub struct AnswerStore {
+    answers: BTreeMap<AnswerKey, String>,
+}
+
+impl AnswerStore {
+    pub fn parse(text: &str) -> AocResult<Self> {
+        let mut store = AnswerStore::default();
+
+        for (idx, raw) in text.lines().enumerate() {
+            let line = raw.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() != 4 {
+                let message = format!("expected `<day> <part> <input> <answer>`, got `{}`", line);
+                return Err(AocError::parse(1, column_of(raw, line), message).at_line(idx + 1));
+            }
+
+            let day = parse::value(raw, fields[0], "day").map_err(|e| e.at_line(idx + 1))?;
+            let part = parse::value(raw, fields[1], "part").map_err(|e| e.at_line(idx + 1))?;
+            store.insert(AnswerKey::new(day, part, fields[2]), fields[3]);
+        }
+
+        Ok(store)
+    }
+
+    pub fn load(path: &str) -> AocResult<Self> {
+        if !Path::new(path).exists() {
+            return Ok(AnswerStore::default());
+        }
+
+        AnswerStore::parse(&fs::read_to_string(path)?)
+    }
+
+    pub fn save(&self, path: &str) -> io::Result<()> {
+        fs::write(path, self.to_text())
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = String::from("# day part input answer\n");
+        for (key, answer) in &self.answers {
+            text.push_str(&format!("{:02} {} {} {}\n", key.day, key.part, key.input, answer));
+        }
+        text
+    }
+
+    pub fn get(&self, key: &AnswerKey) -> Option<&str> {
+        self.answers.get(key).map(|a| a.as_str())
+    }
+
+    pub fn insert(&mut self, key: AnswerKey, answer: &str) {
+        self.answers.insert(key, answer.to_string());
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &AnswerKey> {
+        self.answers.keys()
+    }
+
+    pub fn compare(&self, computed: &[(AnswerKey, String)]) -> Comparison {
+        let mut comparison = Comparison::default();
+
+        for (key, answer) in computed {
+            match self.get(key) {
+                Some(expected) if expected == answer => {
+                    comparison.matched.push(key.clone());
+                }
+                Some(expected) => {
+                    comparison.mismatches.push(Mismatch {
+                        key: key.clone(),
+                        expected: expected.to_string(),
+                        actual: answer.clone(),
+                    });
+                }
+                None => {
+                    comparison.new.push((key.clone(), answer.clone()));
+                }
+            }
+        }
+
+        for key in self.keys() {
+            if !computed.iter().any(|(k, _)| k == key) {
+                comparison.missing.push(key.clone());
+            }
+        }
+
+        comparison
+    }
+}
+
+// For tests on the personal input: checks `answer` against the one recorded in answers.txt,
+// answers nobody recorded yet are only reported
+#[cfg(test)]
+pub fn assert_recorded(day: u8, part: u8, answer: impl std::fmt::Display) {
+    use std::io::Write;
+
+    let key = AnswerKey::new(day, part, "input");
+    let store = AnswerStore::load(DEFAULT_PATH).unwrap();
+    match store.get(&key) {
+        Some(expected) => assert_eq!(answer.to_string(), expected, "{}", key),
+        None => {
+            let _ = writeln!(io::stderr(), "{}: {} is not recorded yet", key, answer);
+        }
+    }
+}
+
+pub struct Mismatch {
+    pub key: AnswerKey,
+    pub expected: String,
+    pub actual: String,
+}
+
+// `missing` holds recorded answers nothing was computed for,
+// `new` holds computed answers that were never recorded
+#[derive(Default)]
+pub struct Comparison {
+    pub matched: Vec<AnswerKey>,
+    pub mismatches: Vec<Mismatch>,
+    pub missing: Vec<AnswerKey>,
+    pub new: Vec<(AnswerKey, String)>,
+}
+
+impl Comparison {
+    pub fn is_ok(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_render_roundtrip() {
+        let text =
+            "# day part input answer\n03 1 example 357\n03 1 input 16946\n\n08 2 example 25272\n";
+        let store = AnswerStore::parse(text).unwrap();
+
+        assert_eq!(store.get(&AnswerKey::new(3, 1, "input")), Some("16946"));
+        assert_eq!(store.get(&AnswerKey::new(8, 2, "example")), Some("25272"));
+        assert_eq!(store.get(&AnswerKey::new(8, 1, "example")), None);
+        assert_eq!(store.to_text(), text.replace("\n\n", "\n"));
+    }
+
+    #[test]
+    fn parse_rejects_malformed_line() {
+        let err = AnswerStore::parse("01 1 example 3\n01 x example 6\n").err().unwrap();
+        assert_eq!(err.to_string(), "parse error at line 2, column 4: invalid part `x`");
+
+        let err = AnswerStore::parse("\n  01 1 example\n").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "parse error at line 2, column 3: expected `<day> <part> <input> <answer>`, \
+             got `01 1 example`"
+        );
+    }
+
+    #[test]
+    fn compare_sorts_results() {
+        let store = AnswerStore::parse("01 1 example 3\n01 2 example 6\n02 1 example 9\n").unwrap();
+        let computed = vec![
+            (AnswerKey::new(1, 1, "example"), "3".to_string()),
+            (AnswerKey::new(1, 2, "example"), "7".to_string()),
+            (AnswerKey::new(3, 1, "example"), "357".to_string())
+        ];
+
+        let comparison = store.compare(&computed);
+
+        assert_eq!(comparison.matched, vec![AnswerKey::new(1, 1, "example")]);
+        assert_eq!(comparison.mismatches.len(), 1);
+        assert_eq!(comparison.mismatches[0].expected, "6");
+        assert_eq!(comparison.mismatches[0].actual, "7");
+        assert_eq!(comparison.missing, vec![AnswerKey::new(2, 1, "example")]);
+        assert_eq!(comparison.new.len(), 1);
+        assert!(!comparison.is_ok());
+    }
+}
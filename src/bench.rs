@@ -0,0 +1,155 @@
+use std::time::Duration;
+
+use crate::memory::AllocStats;
+use crate::registry::Day;
+use crate::AocResult;
+
+pub const DEFAULT_ITERATIONS: usize = 10;
+
+#[derive(Debug, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        Stats {
+            min: sorted.first().copied().unwrap_or_default(),
+            median: sorted.get(sorted.len() / 2).copied().unwrap_or_default(),
+            max: sorted.last().copied().unwrap_or_default(),
+        }
+    }
+}
+
+pub struct Phase {
+    pub name: String,
+    pub stats: Stats,
+    // Taken from the last iteration, allocations do not vary between runs
+    pub memory: Option<AllocStats>,
+}
+
+pub struct BenchResult {
+    pub day: u8,
+    pub input: String,
+    pub iterations: usize,
+    pub phases: Vec<Phase>,
+}
+
+// Every iteration parses from scratch so parse and part timings come from the same runs
+pub fn bench_day(
+    day: &Day,
+    input_name: &str,
+    input: &str,
+    iterations: usize
+) -> AocResult<BenchResult> {
+    let mut parse_samples = Vec::with_capacity(iterations);
+    let mut part_samples: Vec<Vec<Duration>> = vec![Vec::new(); day.parts.len()];
+    let mut last_run = None;
+
+    for _ in 0..iterations.max(1) {
+        let run = (day.run)(input, day.parts)?;
+        parse_samples.push(run.parse_elapsed);
+        for (samples, part) in part_samples.iter_mut().zip(run.parts.iter()) {
+            samples.push(part.elapsed);
+        }
+        last_run = Some(run);
+    }
+    let last_run = last_run.expect("at least one iteration ran");
+
+    let mut phases = vec![Phase {
+        name: "parse".to_string(),
+        stats: Stats::from_samples(&parse_samples),
+        memory: last_run.parse_memory,
+    }];
+    for (result, samples) in last_run.parts.iter().zip(part_samples.iter()) {
+        phases.push(Phase {
+            name: format!("part{}", result.part),
+            stats: Stats::from_samples(samples),
+            memory: result.memory,
+        });
+    }
+
+    Ok(BenchResult {
+        day: day.day,
+        input: input_name.to_string(),
+        iterations: iterations.max(1),
+        phases,
+    })
+}
+
+// Tab separated, one phase per line, durations in nanoseconds. The allocation columns stay
+// empty unless the counting allocator is installed.
+pub fn to_tsv(results: &[BenchResult]) -> String {
+    let mut text = String::from(
+        "day\tinput\tphase\titerations\tmin_ns\tmedian_ns\tmax_ns\tallocs\tbytes\tpeak_bytes\n"
+    );
+    for result in results {
+        for phase in &result.phases {
+            let memory = match phase.memory {
+                Some(stats) => format!("{}\t{}\t{}", stats.allocations, stats.bytes, stats.peak),
+                None => "\t\t".to_string(),
+            };
+            text.push_str(
+                &format!(
+                    "{:02}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\n",
+                    result.day,
+                    result.input,
+                    phase.name,
+                    result.iterations,
+                    phase.stats.min.as_nanos(),
+                    phase.stats.median.as_nanos(),
+                    phase.stats.max.as_nanos(),
+                    memory
+                )
+            );
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn stats_from_samples() {
+        let samples: Vec<Duration> = [5, 1, 3, 9, 7]
+            .iter()
+            .map(|&ms| Duration::from_millis(ms))
+            .collect();
+        let stats = Stats::from_samples(&samples);
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(5));
+        assert_eq!(stats.max, Duration::from_millis(9));
+    }
+
+    #[test]
+    fn bench_example_input() {
+        let day = registry::find(4).unwrap();
+        let result = bench_day(day, "example", day.example, 3).unwrap();
+
+        let names: Vec<&str> = result.phases
+            .iter()
+            .map(|p| p.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["parse", "part1", "part2"]);
+        for phase in &result.phases {
+            assert!(phase.stats.min <= phase.stats.median);
+            assert!(phase.stats.median <= phase.stats.max);
+        }
+
+        let tsv = to_tsv(&[result]);
+        assert_eq!(tsv.lines().count(), 4);
+        assert!(tsv.lines().nth(1).unwrap().starts_with("04\texample\tparse\t3\t"));
+        // Unit tests run with the counting allocator, parsing the grid allocates
+        let allocs = tsv.lines().nth(1).unwrap().split('\t').nth(7).unwrap();
+        assert!(allocs.parse::<u64>().unwrap() > 0);
+    }
+}
@@ -1,5 +1,4 @@
-use std::fs::File;
-use std::io::{ BufRead, BufReader };
+use crate::{ AocError, AocResult, Solver };
 
 struct DialRange {
     pub min: i32,
@@ -16,8 +15,8 @@ struct DialClock {
     pub config: DialConfig,
 }
 
-#[derive(Debug, PartialEq)]
-enum DialDirection {
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum DialDirection {
     Left,
     Right,
 }
@@ -28,47 +27,55 @@ enum DialDirectionBuildType {
     Explode,
 }
 
-#[derive(Debug)]
-struct DialMovement {
+#[derive(Debug, Clone)]
+pub struct DialMovement {
     pub steps: i32,
     pub direction: DialDirection,
 }
 
 impl DialMovement {
-    pub fn from_string(s: &str) -> Self {
-        let direction = s.chars().next().unwrap();
-        let steps: i32 = s[1..].parse().unwrap();
+    pub fn from_string(s: &str) -> AocResult<Self> {
+        let direction = s
+            .chars()
+            .next()
+            .ok_or(AocError::parse(1, 1, "expected a movement, got an empty line"))?;
+        let steps_str = &s[direction.len_utf8()..];
+        let steps: i32 = steps_str
+            .parse()
+            .map_err(|_| AocError::parse(1, 2, format!("invalid step count `{}`", steps_str)))?;
+        if steps < 0 {
+            return Err(AocError::parse(1, 2, format!("negative step count `{}`", steps_str)));
+        }
 
         let dial_direction = match direction {
             'L' => DialDirection::Left,
             'R' => DialDirection::Right,
-            _ => panic!("Invalid direction"),
+            _ => {
+                return Err(
+                    AocError::parse(
+                        1,
+                        1,
+                        format!("invalid direction `{}`, expected L or R", direction)
+                    )
+                );
+            }
         };
 
-        DialMovement {
+        Ok(DialMovement {
             steps,
             direction: dial_direction,
-        }
+        })
     }
 
-    pub fn from_string_explode(s: &str) -> Vec<Self> {
-        let (dir_char, steps_str) = s.split_at(1);
-        let steps: i32 = steps_str.parse().unwrap();
-
-        let dial_direction = match dir_char {
-            "L" => DialDirection::Left,
-            "R" => DialDirection::Right,
-            _ => panic!("Invalid direction"),
-        };
+    pub fn from_string_explode(s: &str) -> AocResult<Vec<Self>> {
+        Ok(DialMovement::from_string(s)?.explode())
+    }
 
-        (0..steps)
+    pub fn explode(&self) -> Vec<Self> {
+        (0..self.steps)
             .map(|_| DialMovement {
                 steps: 1,
-                direction: if dial_direction == DialDirection::Left {
-                    DialDirection::Left
-                } else {
-                    DialDirection::Right
-                },
+                direction: self.direction,
             })
             .collect()
     }
@@ -82,15 +89,30 @@ impl DialClock {
         }
     }
 
-    fn turn(&mut self, movement: DialMovement) {
+    // Returns how many clicks of the movement leave the dial at 0. Worked out from the distance
+    // to the first 0, so any step count is fine.
+    fn turn(&mut self, movement: DialMovement) -> i64 {
+        let range = &self.config.range;
+        let range_size = range.max - range.min + 1;
+        let to_zero = match movement.direction {
+            DialDirection::Left => self.position.rem_euclid(range_size),
+            DialDirection::Right => (-self.position).rem_euclid(range_size),
+        };
+        let first_zero = if to_zero == 0 { range_size } else { to_zero };
+        let zeros = if range.min <= 0 && 0 <= range.max && movement.steps >= first_zero {
+            ((movement.steps - first_zero) / range_size + 1) as i64
+        } else {
+            0
+        };
+
+        // Whole turns change nothing, dropping them first keeps the sum in range
+        let steps = movement.steps % range_size;
         let delta = match movement.direction {
-            DialDirection::Left => -movement.steps,
-            DialDirection::Right => movement.steps,
+            DialDirection::Left => -steps,
+            DialDirection::Right => steps,
         };
-        self.position += delta;
-        let range_size = self.config.range.max - self.config.range.min + 1;
-        self.position =
-            self.config.range.min + (self.position - self.config.range.min).rem_euclid(range_size);
+        self.position = range.min + (self.position + delta - range.min).rem_euclid(range_size);
+        zeros
     }
 
     pub fn get_position(&self) -> i32 {
@@ -98,52 +120,71 @@ impl DialClock {
     }
 }
 
-struct Solution {}
+pub struct Solution {}
 
 impl Solution {
-    fn iterate_input<F>(self, file_name: &str, build_type: DialDirectionBuildType, mut callback: F)
-        where F: FnMut(DialMovement)
+    // Zeros at the end of each movement, or at every click when `by_click` is set
+    fn count_zeros<I>(movements: I, by_click: bool) -> i64
+        where I: IntoIterator<Item = DialMovement>
     {
-        let file = File::open(file_name).unwrap();
-        let reader = BufReader::new(file);
-
-        for line in reader.lines() {
-            let line = line;
-            if build_type == DialDirectionBuildType::Explode {
-                let movement = DialMovement::from_string_explode(&line.unwrap());
-                for m in movement {
-                    callback(m);
-                }
-            } else {
-                let movement = vec![DialMovement::from_string(&line.unwrap())];
-                for m in movement {
-                    callback(m);
-                }
-            }
-        }
-    }
-
-    fn run(self, build_type: DialDirectionBuildType, file_name: &str) -> i32 {
         let mut zero_count = 0;
         let mut dial = DialClock::new(DialConfig {
             range: DialRange { min: 0, max: 99 },
             start_position: 50,
         });
 
-        self.iterate_input(file_name, build_type, |movement| {
-            dial.turn(movement);
-            if dial.get_position() == 0 {
+        for movement in movements {
+            let clicks = dial.turn(movement);
+            if by_click {
+                zero_count += clicks;
+            } else if dial.get_position() == 0 {
                 zero_count += 1;
             }
-        });
+        }
+
+        zero_count
+    }
+
+    fn run(self, build_type: DialDirectionBuildType, file_name: &str) -> AocResult<i64> {
+        let movements = Solution::parse_file(file_name)?;
+
+        Ok(match build_type {
+            DialDirectionBuildType::Single => Solution::part_one(&movements),
+            DialDirectionBuildType::Explode => Solution::part_two(&movements),
+        })
+    }
+}
+
+impl Solver for Solution {
+    type Input = Vec<DialMovement>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    const TITLE: &'static str = "Secret Entrance";
+    const EXAMPLE: &'static str = include_str!("../input/day_01_example.txt");
+
+    fn parse(input: &str) -> AocResult<Vec<DialMovement>> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| DialMovement::from_string(line).map_err(|e| e.at_line(idx + 1)))
+            .collect()
+    }
+
+    fn part_one(movements: &Vec<DialMovement>) -> i64 {
+        Solution::count_zeros(movements.iter().cloned(), false)
+    }
 
-        return zero_count;
+    fn part_two(movements: &Vec<DialMovement>) -> i64 {
+        Solution::count_zeros(movements.iter().cloned(), true)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::answers;
+    use crate::property;
 
     fn setup() -> DialClock {
         DialClock::new(DialConfig {
@@ -154,35 +195,47 @@ mod tests {
 
     #[test]
     fn test_solution_run_on_example_part1() {
-        let solution = Solution {};
-        let res = solution.run(DialDirectionBuildType::Single, "./input/day_01_example.txt");
-        println!("Example result: {}", res);
+        let movements = Solution::parse(Solution::EXAMPLE).unwrap();
+        let res = Solution::part_one(&movements);
+        assert_eq!(res, 3);
     }
 
     #[test]
     fn test_solution_run_on_input_part1() {
+        require_input!("./input/day_01.txt");
+
         let solution = Solution {};
-        let res = solution.run(DialDirectionBuildType::Single, "./input/day_01.txt");
-        println!("Result: {}", res);
+        let res = solution.run(DialDirectionBuildType::Single, "./input/day_01.txt").unwrap();
+        answers::assert_recorded(1, 1, res);
     }
 
     #[test]
     fn test_solution_run_on_example_part2() {
-        let solution = Solution {};
-        let res = solution.run(DialDirectionBuildType::Explode, "./input/day_01_example.txt");
-        println!("Example result part 2: {}", res);
+        let movements = Solution::parse(Solution::EXAMPLE).unwrap();
+        let res = Solution::part_two(&movements);
+        assert_eq!(res, 6);
     }
 
     #[test]
     fn test_solution_run_on_input_part2() {
+        require_input!("./input/day_01.txt");
+
         let solution = Solution {};
-        let res = solution.run(DialDirectionBuildType::Explode, "./input/day_01.txt");
-        println!("Result part 2: {}", res);
+        let res = solution.run(DialDirectionBuildType::Explode, "./input/day_01.txt").unwrap();
+        answers::assert_recorded(1, 2, res);
+    }
+
+    #[test]
+    fn huge_step_counts() {
+        let movements = Solution::parse("R2147483647\nL2147483647\n").unwrap();
+        assert_eq!(Solution::part_one(&movements), 0);
+        // First 0 after 50 clicks then every 100, back from 97 it is first after 97 clicks
+        assert_eq!(Solution::part_two(&movements), 21474836 + 21474836);
     }
 
     #[test]
     fn test_movement_build_explode() {
-        let test1 = DialMovement::from_string_explode("R10");
+        let test1 = DialMovement::from_string_explode("R10").unwrap();
         assert_eq!(test1.len(), 10);
         for movement in test1 {
             assert_eq!(movement.steps, 1);
@@ -192,15 +245,36 @@ mod tests {
 
     #[test]
     fn test_movement_build() {
-        let test1 = DialMovement::from_string("R10");
+        let test1 = DialMovement::from_string("R10").unwrap();
         assert_eq!(test1.steps, 10);
         assert_eq!(test1.direction, DialDirection::Right);
 
-        let test2 = DialMovement::from_string("L25");
+        let test2 = DialMovement::from_string("L25").unwrap();
         assert_eq!(test2.steps, 25);
         assert_eq!(test2.direction, DialDirection::Left);
     }
 
+    #[test]
+    fn test_movement_build_errors() {
+        let err = DialMovement::from_string("X10").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "parse error at line 1, column 1: invalid direction `X`, expected L or R"
+        );
+
+        let err = DialMovement::from_string("R1x").err().unwrap();
+        assert_eq!(err.to_string(), "parse error at line 1, column 2: invalid step count `1x`");
+
+        assert!(DialMovement::from_string("").is_err());
+        assert!(DialMovement::from_string("é").is_err());
+    }
+
+    #[test]
+    fn test_parse_reports_line() {
+        let err = Solution::parse("R10\nL5\nR\n").err().unwrap();
+        assert_eq!(err.to_string(), "parse error at line 3, column 2: invalid step count ``");
+    }
+
     #[test]
     fn test_dial_init() {
         let dial = setup();
@@ -241,4 +315,91 @@ mod tests {
 
         assert_eq!(dial.get_position(), 55);
     }
+
+    fn movements(moves: &[(bool, u32)]) -> Vec<DialMovement> {
+        moves
+            .iter()
+            .map(|&(left, steps)| DialMovement {
+                steps: steps as i32,
+                direction: if left { DialDirection::Left } else { DialDirection::Right },
+            })
+            .collect()
+    }
+
+    #[test]
+    fn turn_stays_inside_range() {
+        property::check(
+            |rng| {
+                let min = rng.range(0, 200) as i32 - 100;
+                let moves = (0..rng.range(0, 30))
+                    .map(|_| (rng.percent(50), rng.range(0, 500) as u32))
+                    .collect::<Vec<_>>();
+                (min, rng.range(0, 150) as u32, moves)
+            },
+            |(min, width, moves)| {
+                let range = DialRange { min: *min, max: min + *width as i32 };
+                let mut dial = DialClock::new(DialConfig {
+                    start_position: min + (*width as i32) / 2,
+                    range,
+                });
+                for movement in movements(moves) {
+                    dial.turn(movement);
+                    let position = dial.get_position();
+                    let range = &dial.config.range;
+                    ensure!(
+                        range.min <= position && position <= range.max,
+                        "position {} outside {}..={}",
+                        position,
+                        range.min,
+                        range.max
+                    );
+                }
+                Ok(())
+            }
+        );
+    }
+
+    #[test]
+    fn counted_clicks_match_single_steps() {
+        property::check(
+            |rng| {
+                (0..rng.range(0, 30))
+                    .map(|_| (rng.percent(50), rng.range(0, 300) as u32))
+                    .collect::<Vec<_>>()
+            },
+            |moves| {
+                let movements = movements(moves);
+                let counted = Solution::part_two(&movements);
+                let single_steps = movements.iter().flat_map(|m| m.explode());
+                let stepped = Solution::count_zeros(single_steps, false);
+                ensure!(counted == stepped, "counted {} zeros, stepped {}", counted, stepped);
+                Ok(())
+            }
+        );
+    }
+
+    #[test]
+    fn every_click_sees_at_least_the_final_zeros() {
+        property::check(
+            |rng| {
+                (0..rng.range(0, 30))
+                    .map(|_| (rng.percent(50), rng.range(0, 300) as u32))
+                    .collect::<Vec<_>>()
+            },
+            |moves| {
+                let movements = movements(moves);
+                let (ends, clicks) = (
+                    Solution::part_one(&movements),
+                    Solution::part_two(&movements),
+                );
+                ensure!(
+                    clicks >= ends,
+                    "{} zeros by click but {} at the end of moves",
+                    clicks,
+                    ends
+                );
+                Ok(())
+            }
+        );
+    }
 }
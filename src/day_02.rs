@@ -1,30 +1,33 @@
-use std::fs::File;
-use std::io::{ BufRead, BufReader };
+use crate::error::column_of;
+use crate::parse;
+use crate::{ AocError, AocResult, Solver };
 
-struct Solution;
+pub struct Solution;
 
 #[derive(PartialEq, Copy, Clone)]
-enum Pattern {
+pub enum Pattern {
     Silly,
     Regular,
 }
 
-struct Range {
+pub struct Range {
     min: u64,
     max: u64,
 }
 
 impl Range {
-    pub fn from_string(s: &str) -> Vec<Range> {
-        let parts: Vec<&str> = s.split(',').collect();
-        parts
-            .iter()
+    pub fn from_string(s: &str) -> AocResult<Vec<Range>> {
+        s.split(',')
             .map(|part| {
-                let bounds: Vec<&str> = part.split('-').collect();
-                Range {
-                    min: bounds[0].parse::<u64>().unwrap(),
-                    max: bounds[1].parse::<u64>().unwrap(),
+                let (min, max) = parse::pair(part, '-', "min-max", "bound").map_err(|e| {
+                    e.offset_column(column_of(s, part) - 1)
+                })?;
+                let range = Range { min, max };
+
+                if range.min > range.max {
+                    return Err(AocError::invalid_at(1, format!("range `{}` is reversed", part)));
                 }
+                Ok(range)
             })
             .collect()
     }
@@ -38,27 +41,25 @@ impl RangeValidator {
     pub fn count_valid(&self, range: &Range) -> i64 {
         let mut valid_count: i64 = 0;
         for val in range.min..=range.max {
-            if self.pattern == Pattern::Regular {
-                if !RangeValidator::is_valid_value(&val.to_string()) {
-                    valid_count += val as i64;
-                }
-            } else {
-                if !RangeValidator::is_valid_value_silly(&val.to_string()) {
-                    valid_count += val as i64;
-                }
+            let valid = match self.pattern {
+                Pattern::Regular => RangeValidator::is_valid_value(&val.to_string()),
+                Pattern::Silly => RangeValidator::is_valid_value_silly(&val.to_string()),
+            };
+            if !valid {
+                valid_count += val as i64;
             }
         }
         valid_count
     }
 
     pub fn is_valid_value(val: &str) -> bool {
-        if val.len() % 2 != 0 {
+        if !val.len().is_multiple_of(2) {
             return true;
         }
 
         let half_val = val.len() / 2;
         let (first_half, second_half) = val.split_at(half_val);
-        return first_half != second_half;
+        first_half != second_half
     }
 
     pub fn is_valid_value_silly(val: &str) -> bool {
@@ -82,56 +83,107 @@ impl RangeValidator {
 
 #[allow(dead_code)]
 impl Solution {
-    pub fn solve(file_path: &str, pattern: Pattern) -> i64 {
-        let file = File::open(file_path).unwrap();
-        let reader = BufReader::new(file);
-        let first_line = reader.lines().next().unwrap().unwrap();
-
-        let mut valid_count: i64 = 0;
-        let range = Range::from_string(&first_line);
+    fn sum_invalid(ranges: &[Range], pattern: Pattern) -> i64 {
         let validator = RangeValidator { pattern };
-        for r in range.iter() {
-            valid_count += validator.count_valid(r);
-        }
-        println!(
-            "Day02 {} solution: {}",
+        ranges
+            .iter()
+            .map(|r| validator.count_valid(r))
+            .sum()
+    }
+
+    pub fn solve(file_path: &str, pattern: Pattern) -> AocResult<i64> {
+        let ranges = Solution::parse_file(file_path)?;
+        let valid_count = Solution::sum_invalid(&ranges, pattern);
+        info!(
+            "{} solution: {}",
             match pattern {
                 Pattern::Regular => "Part 1",
                 Pattern::Silly => "Part 2",
             },
             valid_count
         );
-        valid_count
+        Ok(valid_count)
+    }
+}
+
+impl Solver for Solution {
+    type Input = Vec<Range>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    const TITLE: &'static str = "Gift Shop";
+    const EXAMPLE: &'static str = include_str!("../input/day_02_example.txt");
+
+    fn parse(input: &str) -> AocResult<Vec<Range>> {
+        let first_line = input
+            .lines()
+            .next()
+            .ok_or(AocError::invalid("expected a line of ranges, got an empty input"))?;
+        Range::from_string(first_line)
+    }
+
+    fn part_one(ranges: &Vec<Range>) -> i64 {
+        Solution::sum_invalid(ranges, Pattern::Regular)
+    }
+
+    fn part_two(ranges: &Vec<Range>) -> i64 {
+        Solution::sum_invalid(ranges, Pattern::Silly)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::answers;
 
     #[test]
     fn solve_part_2_example() {
-        Solution::solve("./input/day_02_example.txt", Pattern::Silly);
+        let ranges = Solution::parse(Solution::EXAMPLE).unwrap();
+        assert_eq!(Solution::sum_invalid(&ranges, Pattern::Silly), 4174379265);
     }
 
     #[test]
     fn solve_part_2() {
-        Solution::solve("./input/day_02.txt", Pattern::Silly);
+        require_input!("./input/day_02.txt");
+
+        let sum = Solution::solve("./input/day_02.txt", Pattern::Silly).unwrap();
+        answers::assert_recorded(2, 2, sum);
     }
 
     #[test]
     fn solve_part_1_example() {
-        Solution::solve("./input/day_02_example.txt", Pattern::Regular);
+        let ranges = Solution::parse(Solution::EXAMPLE).unwrap();
+        assert_eq!(Solution::sum_invalid(&ranges, Pattern::Regular), 1227775554);
     }
 
     #[test]
     fn solve_part_1() {
-        Solution::solve("./input/day_02.txt", Pattern::Regular);
+        require_input!("./input/day_02.txt");
+
+        let sum = Solution::solve("./input/day_02.txt", Pattern::Regular).unwrap();
+        answers::assert_recorded(2, 1, sum);
+    }
+
+    #[test]
+    fn test_range_parse_errors() {
+        let err = Range::from_string("11-22,95").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "parse error at line 1, column 7: expected `min-max`, got `95`"
+        );
+
+        let err = Range::from_string("11-22,95-x").err().unwrap();
+        assert_eq!(err.to_string(), "parse error at line 1, column 10: invalid bound `x`");
+
+        let err = Range::from_string("22-11").err().unwrap();
+        assert_eq!(err.to_string(), "invalid input at line 1: range `22-11` is reversed");
+
+        assert!(Solution::parse("").is_err());
     }
 
     #[test]
     fn test_values() {
-        let incorrect = vec![
+        let incorrect = [
             "11",
             "22",
             "99",
@@ -142,21 +194,21 @@ mod tests {
             "38593859"
         ];
         for val in incorrect.iter() {
-            assert_eq!(RangeValidator::is_valid_value(val), false);
+            assert!(!RangeValidator::is_valid_value(val));
         }
 
-        let correct = vec!["12", "101"];
+        let correct = ["12", "101"];
         for val in correct.iter() {
-            assert_eq!(RangeValidator::is_valid_value(val), true);
+            assert!(RangeValidator::is_valid_value(val));
         }
     }
 
     #[test]
     fn test_silly_pattern() {
-        assert_eq!(RangeValidator::is_valid_value_silly("12341234"), false);
-        assert_eq!(RangeValidator::is_valid_value_silly("123123123"), false);
-        assert_eq!(RangeValidator::is_valid_value_silly("1212121212"), false);
-        assert_eq!(RangeValidator::is_valid_value_silly("1111111"), false);
+        assert!(!RangeValidator::is_valid_value_silly("12341234"));
+        assert!(!RangeValidator::is_valid_value_silly("123123123"));
+        assert!(!RangeValidator::is_valid_value_silly("1212121212"));
+        assert!(!RangeValidator::is_valid_value_silly("1111111"));
     }
 
     #[test]
@@ -1,13 +1,17 @@
-use std::fs::File;
-use std::io::{ BufRead, BufReader };
+use crate::{ AocError, AocResult, Solver };
 
-struct Battery {
+const PART_ONE_CAPACITY: i32 = 2;
+const PART_TWO_CAPACITY: i32 = 12;
+
+pub struct Battery {
     bank: String,
     capacity: i64,
 }
 
 impl Battery {
-    pub fn from_string(s: &str, capacity_len: i32) -> Self {
+    pub fn from_string(s: &str, capacity_len: i32) -> AocResult<Self> {
+        Battery::validate_bank(s, capacity_len)?;
+
         let mut capacity_vec = Vec::<String>::new();
         let mut last_index: i32 = 0;
 
@@ -20,16 +24,39 @@ impl Battery {
             last_index += last_index_offset + 1;
         }
 
-        Battery {
+        let capacity = capacity_vec
+            .join("")
+            .parse::<i64>()
+            .map_err(|_| {
+                AocError::invalid_at(1, format!("capacity of {} digits overflows", capacity_len))
+            })?;
+
+        Ok(Battery {
             bank: s.to_string(),
-            capacity: capacity_vec.join("").parse::<i64>().unwrap(),
+            capacity,
+        })
+    }
+
+    fn validate_bank(s: &str, capacity_len: i32) -> AocResult<()> {
+        if let Some((idx, c)) = s.chars().enumerate().find(|(_, c)| !c.is_ascii_digit()) {
+            return Err(AocError::parse(1, idx + 1, format!("expected a digit, got `{}`", c)));
+        }
+        let needed = capacity_len.max(1) as usize;
+        if s.len() < needed {
+            return Err(
+                AocError::invalid_at(
+                    1,
+                    format!("bank has {} batteries, at least {} are needed", s.len(), needed)
+                )
+            );
         }
+        Ok(())
     }
 
     fn find_max(s: &str, size: i32) -> [i32; 2] {
         let nums_vec = s
             .chars()
-            .map(|x| x.to_string().parse::<i32>().unwrap())
+            .map(|x| x.to_digit(10).expect("banks are validated") as i32)
             .collect::<Vec<i32>>();
 
         let mut largest_num = 0;
@@ -54,23 +81,23 @@ impl Battery {
     }
 }
 
-struct Solution;
+pub struct Solution;
 
 #[allow(dead_code)]
 impl Solution {
-    pub fn build_input(file_name: &str, capacity_len: i32) -> Vec<Battery> {
-        let mut battery_vec: Vec<Battery> = Vec::new();
-        let file = File::open(file_name).unwrap();
-        let reader = BufReader::new(file);
-
-        for line in reader.lines() {
-            let line = line;
-            let line_value = line.unwrap();
-            let battery = Battery::from_string(&line_value, capacity_len);
-            battery_vec.push(battery);
-        }
+    pub fn build_batteries(banks: &[String], capacity_len: i32) -> AocResult<Vec<Battery>> {
+        banks
+            .iter()
+            .enumerate()
+            .map(|(idx, bank)| {
+                Battery::from_string(bank, capacity_len).map_err(|e| e.at_line(idx + 1))
+            })
+            .collect()
+    }
 
-        battery_vec
+    pub fn build_input(file_name: &str, capacity_len: i32) -> AocResult<Vec<Battery>> {
+        let banks = Solution::parse_file(file_name)?;
+        Solution::build_batteries(&banks, capacity_len)
     }
 
     pub fn solve(batteries: Vec<Battery>) -> i64 {
@@ -81,22 +108,86 @@ impl Solution {
     }
 }
 
+impl Solver for Solution {
+    type Input = Vec<String>;
+    type Answer1 = AocResult<i64>;
+    type Answer2 = AocResult<i64>;
+
+    const TITLE: &'static str = "Lobby";
+    const EXAMPLE: &'static str = include_str!("../input/day_03_example.txt");
+
+    // Banks only need enough batteries for part one, part two reports the ones that are short
+    fn parse(input: &str) -> AocResult<Vec<String>> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| {
+                Battery::validate_bank(line, PART_ONE_CAPACITY)
+                    .map(|_| line.to_string())
+                    .map_err(|e| e.at_line(idx + 1))
+            })
+            .collect()
+    }
+
+    fn part_one(banks: &Vec<String>) -> AocResult<i64> {
+        let batteries = Solution::build_batteries(banks, PART_ONE_CAPACITY)?;
+        Ok(Solution::solve(batteries))
+    }
+
+    fn part_two(banks: &Vec<String>) -> AocResult<i64> {
+        let batteries = Solution::build_batteries(banks, PART_TWO_CAPACITY)?;
+        Ok(Solution::solve(batteries))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::answers;
 
     #[test]
     fn battery_test_1() {
-        assert_eq!(Battery::from_string("987654321111111", 2).capacity, 98);
-        assert_eq!(Battery::from_string("811111111111119", 2).capacity, 89);
-        assert_eq!(Battery::from_string("811111111111119", 3).capacity, 819);
-        assert_eq!(Battery::from_string("234234234234278", 2).capacity, 78);
-        assert_eq!(Battery::from_string("818181911112111", 2).capacity, 92);
+        assert_eq!(Battery::from_string("987654321111111", 2).unwrap().capacity, 98);
+        assert_eq!(Battery::from_string("811111111111119", 2).unwrap().capacity, 89);
+        assert_eq!(Battery::from_string("811111111111119", 3).unwrap().capacity, 819);
+        assert_eq!(Battery::from_string("234234234234278", 2).unwrap().capacity, 78);
+        assert_eq!(Battery::from_string("818181911112111", 2).unwrap().capacity, 92);
+    }
+
+    #[test]
+    fn battery_parse_errors() {
+        let err = Battery::from_string("98765x321", 2).err().unwrap();
+        assert_eq!(err.to_string(), "parse error at line 1, column 6: expected a digit, got `x`");
+
+        let err = Battery::from_string("98", 3).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "invalid input at line 1: bank has 2 batteries, at least 3 are needed"
+        );
+
+        let err = Solution::parse("987654321111111\n1\n").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "invalid input at line 2: bank has 1 batteries, at least 2 are needed"
+        );
+    }
+
+    #[test]
+    fn short_banks_only_fail_part_two() {
+        let banks = Solution::parse("987654321111111\n1234\n").unwrap();
+        assert_eq!(Solution::part_one(&banks).unwrap(), 98 + 34);
+
+        let err = Solution::part_two(&banks).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "invalid input at line 2: bank has 4 batteries, at least 12 are needed"
+        );
     }
 
     #[test]
     fn solution_part1_example() {
-        let batteries = Solution::build_input("./input/day_03_example.txt", 2);
+        let banks = Solution::parse(Solution::EXAMPLE).unwrap();
+        let batteries = Solution::build_batteries(&banks, 2).unwrap();
         assert_eq!(batteries.len(), 4);
 
         let sum = Solution::solve(batteries);
@@ -105,32 +196,37 @@ mod tests {
 
     #[test]
     fn solution_part1() {
-        let batteries = Solution::build_input("./input/day_03.txt", 2);
+        require_input!("./input/day_03.txt");
+
+        let batteries = Solution::build_input("./input/day_03.txt", 2).unwrap();
         let sum = Solution::solve(batteries);
         assert_eq!(sum, 16946);
     }
 
     #[test]
     fn battery_test_2() {
-        assert_eq!(Battery::from_string("987654321111111", 12).capacity, 987654321111);
-        assert_eq!(Battery::from_string("811111111111119", 12).capacity, 811111111119);
-        assert_eq!(Battery::from_string("234234234234278", 12).capacity, 434234234278);
-        assert_eq!(Battery::from_string("818181911112111", 12).capacity, 888911112111);
+        assert_eq!(Battery::from_string("987654321111111", 12).unwrap().capacity, 987654321111);
+        assert_eq!(Battery::from_string("811111111111119", 12).unwrap().capacity, 811111111119);
+        assert_eq!(Battery::from_string("234234234234278", 12).unwrap().capacity, 434234234278);
+        assert_eq!(Battery::from_string("818181911112111", 12).unwrap().capacity, 888911112111);
     }
 
     #[test]
     fn solution_part2_example() {
-        let batteries = Solution::build_input("./input/day_03_example.txt", 12);
+        let banks = Solution::parse(Solution::EXAMPLE).unwrap();
+        let batteries = Solution::build_batteries(&banks, 12).unwrap();
         assert_eq!(batteries.len(), 4);
 
         let sum = Solution::solve(batteries);
-        println!("Part 2 example solution: {}", sum);
+        assert_eq!(sum, 3121910778619);
     }
 
     #[test]
     fn solution_part2() {
-        let batteries = Solution::build_input("./input/day_03.txt", 12);
+        require_input!("./input/day_03.txt");
+
+        let batteries = Solution::build_input("./input/day_03.txt", 12).unwrap();
         let sum = Solution::solve(batteries);
-        println!("Part 2 solution: {}", sum);
+        answers::assert_recorded(3, 2, sum);
     }
 }
@@ -1,85 +1,53 @@
-use std::fs::File;
-use std::io::{ BufRead, BufReader };
-use std::collections::HashSet;
+use crate::grid::Grid;
+use crate::{ AocResult, Solver };
 
-struct Solution;
+pub struct Solution;
 
-#[derive(PartialEq, Eq, Hash, Copy, Clone)]
-struct Position {
-    x: i32,
-    y: i32,
-}
+// true where a roll of paper `@` sits
+pub type Floor = Grid<bool>;
 
 impl Solution {
-    fn count_adjacent_occupied(pos: &Position, occupied: &HashSet<Position>) -> usize {
-        const DELTAS: [(i32, i32); 8] = [
-            (-1, -1),
-            (0, -1),
-            (1, -1),
-            (-1, 0),
-            (1, 0),
-            (-1, 1),
-            (0, 1),
-            (1, 1),
-        ];
-
-        DELTAS.iter()
-            .filter(|(dx, dy)| {
-                occupied.contains(
-                    &(Position {
-                        x: pos.x + dx,
-                        y: pos.y + dy,
-                    })
-                )
-            })
+    fn count_adjacent_occupied(x: usize, y: usize, floor: &Floor) -> usize {
+        floor
+            .neighbours8(x, y)
+            .filter(|&(nx, ny)| floor.get(nx, ny) == Some(&true))
             .count()
     }
 
-    fn find_accessible(occupied: &HashSet<Position>) -> Vec<Position> {
-        occupied
-            .iter()
-            .filter(|pos| Solution::count_adjacent_occupied(pos, occupied) < 4)
-            .copied()
+    fn find_accessible(floor: &Floor) -> Vec<(usize, usize)> {
+        floor
+            .find_all(|&occupied| occupied)
+            .into_iter()
+            .filter(|&(x, y)| Solution::count_adjacent_occupied(x, y, floor) < 4)
             .collect()
     }
 
-    fn read_input(file_name: &str) -> HashSet<Position> {
-        let file = File::open(file_name).unwrap();
-        let reader = BufReader::new(file);
-        let mut occupied = HashSet::new();
-
-        for (y, line) in reader.lines().enumerate() {
-            for (x, c) in line.unwrap().chars().enumerate() {
-                if c == '@' {
-                    occupied.insert(Position {
-                        x: x as i32,
-                        y: y as i32,
-                    });
-                }
-            }
-        }
+    fn read_input(file_name: &str) -> AocResult<Floor> {
+        Solution::parse_file(file_name)
+    }
 
-        occupied
+    pub fn solve_part1(file_name: &str) -> AocResult<usize> {
+        let occupied = Solution::read_input(file_name)?;
+        Ok(Solution::part_one(&occupied))
     }
 
-    pub fn solve_part1(file_name: &str) -> usize {
-        let occupied = Solution::read_input(file_name);
-        Solution::find_accessible(&occupied).len()
+    pub fn solve_part2(file_name: &str) -> AocResult<i32> {
+        let occupied = Solution::read_input(file_name)?;
+        Ok(Solution::part_two(&occupied))
     }
 
-    pub fn solve_part2(file_name: &str) -> i32 {
-        let mut occupied = Solution::read_input(file_name);
+    fn remove_all_accessible(mut floor: Floor) -> i32 {
         let mut total_removed = 0;
 
         loop {
-            let accessible = Solution::find_accessible(&occupied);
+            let accessible = Solution::find_accessible(&floor);
             if accessible.is_empty() {
                 break;
             }
 
             total_removed += accessible.len() as i32;
-            for pos in accessible {
-                occupied.remove(&pos);
+            for (x, y) in accessible {
+                floor.set(x, y, false);
             }
         }
 
@@ -87,31 +55,71 @@ impl Solution {
     }
 }
 
+impl Solver for Solution {
+    type Input = Floor;
+    type Answer1 = usize;
+    type Answer2 = i32;
+
+    const TITLE: &'static str = "Printing Department";
+    const EXAMPLE: &'static str = include_str!("../input/day_04_example.txt");
+
+    fn parse(input: &str) -> AocResult<Floor> {
+        Grid::parse(input, |c| {
+            match c {
+                '@' => Some(true),
+                '.' => Some(false),
+                _ => None,
+            }
+        })
+    }
+
+    fn part_one(floor: &Floor) -> usize {
+        Solution::find_accessible(floor).len()
+    }
+
+    fn part_two(floor: &Floor) -> i32 {
+        Solution::remove_all_accessible(floor.clone())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Solution;
+    use crate::Solver;
 
     #[test]
     fn test_case_part_1() {
-        let accessible = Solution::solve_part1("./input/day_04_example.txt");
+        let occupied = Solution::parse(Solution::EXAMPLE).unwrap();
+        let accessible = Solution::part_one(&occupied);
         assert_eq!(accessible, 13);
     }
 
     #[test]
     fn test_case_part_2() {
-        let removed = Solution::solve_part2("./input/day_04_example.txt");
+        let occupied = Solution::parse(Solution::EXAMPLE).unwrap();
+        let removed = Solution::part_two(&occupied);
         assert_eq!(removed, 43);
     }
 
+    #[test]
+    fn parse_rejects_unknown_cell() {
+        let err = Solution::parse("..@\n.#@\n").err().unwrap();
+        assert_eq!(err.to_string(), "parse error at line 2, column 2: unexpected cell `#`");
+    }
+
     #[test]
     fn solve_part1() {
-        let accessible = Solution::solve_part1("./input/day_04.txt");
+        require_input!("./input/day_04.txt");
+
+        let accessible = Solution::solve_part1("./input/day_04.txt").unwrap();
         assert_eq!(accessible, 1428);
     }
 
     #[test]
     fn solve_part2() {
-        let removed = Solution::solve_part2("./input/day_04.txt");
+        require_input!("./input/day_04.txt");
+
+        let removed = Solution::solve_part2("./input/day_04.txt").unwrap();
         assert_eq!(removed, 8936);
     }
 }
@@ -1,18 +1,20 @@
-use std::fs::File;
-use std::io::{ BufRead, BufReader };
+use crate::parse;
+use crate::{ AocError, AocResult, Solver };
 
-struct FreshRange {
+pub struct FreshRange {
     start: i64,
     end: i64,
 }
 
 impl FreshRange {
-    pub fn from_string(str: &str) -> Self {
-        let parts: Vec<&str> = str.split('-').collect();
-        let start = parts[0].parse::<i64>().unwrap();
-        let end = parts[1].parse::<i64>().unwrap();
+    pub fn from_string(str: &str) -> AocResult<Self> {
+        let (start, end) = parse::pair(str, '-', "start-end", "bound")?;
+        let range = FreshRange { start, end };
 
-        FreshRange { start, end }
+        if range.start > range.end {
+            return Err(AocError::invalid_at(1, format!("range `{}` is reversed", str)));
+        }
+        Ok(range)
     }
 
     pub fn contains(&self, val: i64) -> bool {
@@ -24,12 +26,12 @@ impl FreshRange {
     }
 }
 
-struct Input {
+pub struct Input {
     pub ranges: Vec<FreshRange>,
     pub values: Vec<i64>,
 }
 
-struct Solution;
+pub struct Solution;
 
 #[allow(dead_code)]
 impl Solution {
@@ -86,49 +88,93 @@ impl Solution {
         count
     }
 
-    pub fn read_input(file_name: &str) -> Input {
-        let mut reading_ranges = true;
-        let file = File::open(file_name).unwrap();
-        let reader = BufReader::new(file);
+    pub fn read_input(file_name: &str) -> AocResult<Input> {
+        Solution::parse_file(file_name)
+    }
+}
+
+impl Solver for Solution {
+    type Input = Input;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    const TITLE: &'static str = "Cafeteria";
+    const EXAMPLE: &'static str = include_str!("../input/day_05_example.txt");
+
+    fn parse(text: &str) -> AocResult<Input> {
+        let sections = parse::sections(text);
+        if let Some(extra) = sections.get(2) {
+            return Err(AocError::invalid_at(extra.line, "unexpected third section"));
+        }
+
         let mut input = Input {
             ranges: Vec::new(),
             values: Vec::new(),
         };
-
-        for line in reader.lines() {
-            let line = line.unwrap();
-
-            if line == "" {
-                reading_ranges = false;
-                continue;
-            }
-
-            if reading_ranges {
-                let range = FreshRange::from_string(&line);
+        if let Some(ranges) = sections.first() {
+            for (line_number, line) in ranges.numbered() {
+                let range = FreshRange::from_string(line).map_err(|e| e.at_line(line_number))?;
                 input.ranges.push(range);
-            } else {
-                let value = line.parse::<i64>().unwrap();
-                input.values.push(value);
+            }
+        }
+        if let Some(values) = sections.get(1) {
+            for (line_number, line) in values.numbered() {
+                let value = parse::value(line, line, "ingredient id");
+                input.values.push(value.map_err(|e| e.at_line(line_number))?);
             }
         }
 
-        input
+        Ok(input)
+    }
+
+    fn part_one(input: &Input) -> i64 {
+        Solution::solve_part1(input)
+    }
+
+    fn part_two(input: &Input) -> i64 {
+        let merged_input = Input {
+            ranges: Solution::merge_ranges(input),
+            values: vec![],
+        };
+        Solution::solve_part2(&merged_input)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeSet;
+
     use super::*;
+    use crate::answers;
+    use crate::property;
 
     #[test]
     fn test_build_range() {
         let input = "2-4";
-        let range = FreshRange::from_string(input);
+        let range = FreshRange::from_string(input).unwrap();
 
         assert_eq!(range.start, 2);
         assert_eq!(range.end, 4);
     }
 
+    #[test]
+    fn test_parse_errors() {
+        let err = FreshRange::from_string("2-x").err().unwrap();
+        assert_eq!(err.to_string(), "parse error at line 1, column 3: invalid bound `x`");
+
+        let err = Solution::parse("3-5\n10\n\n1\n").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "parse error at line 2, column 1: expected `start-end`, got `10`"
+        );
+
+        let err = Solution::parse("3-5\n\n1\nabc\n").err().unwrap();
+        assert_eq!(err.to_string(), "parse error at line 4, column 1: invalid ingredient id `abc`");
+
+        let err = Solution::parse("5-3\n\n1\n").err().unwrap();
+        assert_eq!(err.to_string(), "invalid input at line 1: range `5-3` is reversed");
+    }
+
     #[test]
     fn test_range_contains() {
         let range = FreshRange { start: 2, end: 4 };
@@ -142,7 +188,7 @@ mod tests {
 
     #[test]
     fn test_input_read() {
-        let input = Solution::read_input("./input/day_05_example.txt");
+        let input = Solution::parse(Solution::EXAMPLE).unwrap();
 
         assert_eq!(input.ranges.len(), 4);
         assert_eq!(input.values.len(), 6);
@@ -150,7 +196,7 @@ mod tests {
 
     #[test]
     fn test_part1_example() {
-        let input = Solution::read_input("./input/day_05_example.txt");
+        let input = Solution::parse(Solution::EXAMPLE).unwrap();
         let result = Solution::solve_part1(&input);
 
         assert_eq!(result, 3);
@@ -158,7 +204,9 @@ mod tests {
 
     #[test]
     fn test_part1() {
-        let input = Solution::read_input("./input/day_05.txt");
+        require_input!("./input/day_05.txt");
+
+        let input = Solution::read_input("./input/day_05.txt").unwrap();
         let result = Solution::solve_part1(&input);
 
         assert_eq!(result, 617);
@@ -196,7 +244,7 @@ mod tests {
 
     #[test]
     fn solve_part2_example() {
-        let input = Solution::read_input("./input/day_05_example.txt");
+        let input = Solution::parse(Solution::EXAMPLE).unwrap();
         let merged_ranges = Solution::merge_ranges(&input);
         let merged_input = Input {
             ranges: merged_ranges,
@@ -209,7 +257,9 @@ mod tests {
 
     #[test]
     fn solve_part2() {
-        let input = Solution::read_input("./input/day_05.txt");
+        require_input!("./input/day_05.txt");
+
+        let input = Solution::read_input("./input/day_05.txt").unwrap();
         let merged_ranges = Solution::merge_ranges(&input);
         let merged_input = Input {
             ranges: merged_ranges,
@@ -217,6 +267,47 @@ mod tests {
         };
         let result = Solution::solve_part2(&merged_input);
 
-        assert_eq!(result, 14);
+        answers::assert_recorded(5, 2, result);
+    }
+
+    #[test]
+    fn merged_ranges_are_sorted_disjoint_and_cover_the_same_ids() {
+        property::check(
+            |rng| {
+                (0..rng.range(0, 12))
+                    .map(|_| (rng.range(0, 100) as i64, rng.range(0, 20)))
+                    .collect::<Vec<_>>()
+            },
+            |ranges| {
+                let input = Input {
+                    ranges: ranges
+                        .iter()
+                        .map(|&(start, len)| FreshRange { start, end: start + len as i64 })
+                        .collect(),
+                    values: vec![],
+                };
+                let merged = Solution::merge_ranges(&input);
+
+                for pair in merged.windows(2) {
+                    // Touching ranges should have been merged too
+                    ensure!(
+                        pair[0].end + 1 < pair[1].start,
+                        "{}-{} and {}-{} overlap or touch",
+                        pair[0].start,
+                        pair[0].end,
+                        pair[1].start,
+                        pair[1].end
+                    );
+                }
+                let ids = |ranges: &[FreshRange]| -> BTreeSet<i64> {
+                    ranges
+                        .iter()
+                        .flat_map(|r| r.start..=r.end)
+                        .collect()
+                };
+                ensure!(ids(&merged) == ids(&input.ranges), "merged ranges cover other ids");
+                Ok(())
+            }
+        );
     }
 }
@@ -1,59 +1,111 @@
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{ BufRead, BufReader };
+
+use crate::{ AocError, AocResult, Solver };
 
 #[derive(Debug)]
-enum Operation {
+pub enum Operation {
     Add,
     Subtract,
     Multiply,
     Divide,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 enum ComputeMode {
     Vertical,
     Horizontal,
 }
 
 #[derive(Debug)]
-struct Worksheet {
+pub struct Worksheet {
     nums_vertical: Vec<i64>,
     nums_horizontal: Vec<i64>,
     operation: Operation,
 }
 
 impl Worksheet {
-    fn compute(&self, mode: ComputeMode) -> i64 {
+    // None when the numbers overflow or divide by zero
+    fn compute(&self, mode: ComputeMode) -> Option<i64> {
         let nums = match mode {
             ComputeMode::Vertical => &self.nums_vertical,
             ComputeMode::Horizontal => &self.nums_horizontal,
         };
 
+        let mut nums = nums.iter();
         match self.operation {
-            Operation::Add => nums.iter().sum(),
-            Operation::Subtract => nums.iter().fold(0, |acc, &x| acc - x),
-            Operation::Multiply => nums.iter().product(),
-            Operation::Divide => nums.iter().fold(1, |acc, &x| acc / x),
+            Operation::Add => nums.try_fold(0i64, |acc, &x| acc.checked_add(x)),
+            Operation::Subtract => nums.try_fold(0i64, |acc, &x| acc.checked_sub(x)),
+            Operation::Multiply => nums.try_fold(1i64, |acc, &x| acc.checked_mul(x)),
+            Operation::Divide => nums.try_fold(1i64, |acc, &x| acc.checked_div(x)),
         }
     }
 }
 
-struct Solution;
+pub struct Solution;
 
 #[allow(dead_code)]
 impl Solution {
-    pub fn read_level(file_name: &str) -> Vec<Worksheet> {
+    pub fn read_level(file_name: &str) -> AocResult<Vec<Worksheet>> {
+        Solution::parse_file(file_name)
+    }
+
+    fn parse_number(num_str: &str) -> AocResult<i64> {
+        num_str
+            .parse::<i64>()
+            .map_err(|_| AocError::invalid(format!("number `{}` is too large", num_str)))
+    }
+
+    fn sum_worksheets(worksheets: &[Worksheet], mode: ComputeMode) -> AocResult<i64> {
+        let mut total: i64 = 0;
+        for (idx, ws) in worksheets.iter().enumerate() {
+            total = ws
+                .compute(mode)
+                .and_then(|result| total.checked_add(result))
+                .ok_or_else(|| {
+                    let message = match ws.operation {
+                        Operation::Divide => "divides by zero or overflows",
+                        _ => "overflows",
+                    };
+                    AocError::invalid(format!("worksheet {} {}", idx + 1, message))
+                })?;
+        }
+        Ok(total)
+    }
+}
+
+impl Solver for Solution {
+    type Input = Vec<Worksheet>;
+    type Answer1 = AocResult<i64>;
+    type Answer2 = AocResult<i64>;
+
+    const TITLE: &'static str = "Trash Compactor";
+    const EXAMPLE: &'static str = include_str!("../input/day_06_example.txt");
+    // Numbers are aligned in columns, trailing spaces are part of the layout
+    const TRIM_TRAILING: bool = false;
+
+    fn parse(input: &str) -> AocResult<Vec<Worksheet>> {
         let mut worksheets: Vec<Worksheet> = Vec::new();
 
-        let file = File::open(file_name).unwrap();
-        let reader = BufReader::new(file);
-        let lines: Vec<String> = reader
-            .lines()
-            .map(|l| l.unwrap())
-            .collect();
+        let lines: Vec<&str> = input.lines().collect();
+        if lines.iter().all(|line| line.trim().is_empty()) {
+            return Err(AocError::invalid("expected a worksheet, got an empty input"));
+        }
+        for (y, line) in lines.iter().enumerate() {
+            if let Some((x, c)) = line
+                .chars()
+                .enumerate()
+                .find(|(_, c)| !(c.is_ascii_digit() || " +-*/".contains(*c)))
+            {
+                return Err(AocError::parse(y + 1, x + 1, format!("unexpected character `{}`", c)));
+            }
+        }
 
-        let num_cols = lines[0].len();
+        // Rows may differ in length when trailing spaces were lost, the widest one counts
+        let num_cols = lines
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
         let mut current_worksheet = Worksheet {
             nums_vertical: Vec::new(),
             nums_horizontal: Vec::new(),
@@ -65,9 +117,7 @@ impl Solution {
             let mut column_chars: Vec<char> = Vec::new();
 
             for line in &lines {
-                if let Some(ch) = line.chars().nth(col_idx) {
-                    column_chars.push(ch);
-                }
+                column_chars.push(line.chars().nth(col_idx).unwrap_or(' '));
             }
 
             let last_char = column_chars.last().cloned().unwrap_or(' ');
@@ -79,13 +129,13 @@ impl Solution {
 
             for (row_idx, &ch) in column_chars.iter().enumerate() {
                 if ch.is_numeric() {
-                    let entry = horizontal_nums_buffer.entry(row_idx).or_insert(String::new());
+                    let entry = horizontal_nums_buffer.entry(row_idx).or_default();
                     entry.push(ch);
                 }
             }
 
             if !column_as_number.is_empty() {
-                current_worksheet.nums_vertical.push(column_as_number.parse::<i64>().unwrap());
+                current_worksheet.nums_vertical.push(Solution::parse_number(&column_as_number)?);
             }
 
             if last_char == '+' || last_char == '-' || last_char == '*' || last_char == '/' {
@@ -101,10 +151,10 @@ impl Solution {
             if all_empty || col_idx == num_cols - 1 {
                 for (_, num_str) in horizontal_nums_buffer.drain() {
                     if !num_str.is_empty() {
-                        current_worksheet.nums_horizontal.push(num_str.parse::<i64>().unwrap());
+                        current_worksheet.nums_horizontal.push(Solution::parse_number(&num_str)?);
                     }
                 }
-                // println!("Completed worksheet: {:?}", current_worksheet);
+                trace!("completed worksheet: {:?}", current_worksheet);
                 worksheets.push(current_worksheet);
                 current_worksheet = Worksheet {
                     nums_vertical: Vec::new(),
@@ -114,7 +164,15 @@ impl Solution {
             }
         }
 
-        worksheets
+        Ok(worksheets)
+    }
+
+    fn part_one(worksheets: &Vec<Worksheet>) -> AocResult<i64> {
+        Solution::sum_worksheets(worksheets, ComputeMode::Horizontal)
+    }
+
+    fn part_two(worksheets: &Vec<Worksheet>) -> AocResult<i64> {
+        Solution::sum_worksheets(worksheets, ComputeMode::Vertical)
     }
 }
 
@@ -124,46 +182,82 @@ mod tests {
 
     #[test]
     fn read_level_test() {
-        let worksheets = Solution::read_level("./input/day_06_example.txt");
+        let worksheets = Solution::parse(Solution::EXAMPLE).unwrap();
         assert_eq!(worksheets.len(), 4);
     }
 
+    #[test]
+    fn parse_errors() {
+        let err = Solution::parse("12 3\n4x 5\n*  +\n").err().unwrap();
+        assert_eq!(err.to_string(), "parse error at line 2, column 2: unexpected character `x`");
+
+        assert!(Solution::parse("").is_err());
+        assert!(Solution::parse("\n  \n").is_err());
+        assert!(Solution::parse("99999999999999999999\n+\n").is_err());
+    }
+
+    #[test]
+    fn column_count_ignores_short_first_row() {
+        let trimmed: String = Solution::EXAMPLE
+            .lines()
+            .map(|line| format!("{}\n", line.trim_end()))
+            .collect();
+        let worksheets = Solution::parse(&trimmed).unwrap();
+        assert_eq!(Solution::part_one(&worksheets).unwrap(), 4277556);
+        assert_eq!(Solution::part_two(&worksheets).unwrap(), 3263827);
+    }
+
+    #[test]
+    fn arithmetic_errors() {
+        let worksheets = Solution::parse("12\n0\n/\n").unwrap();
+        let err = Solution::part_one(&worksheets).err().unwrap();
+        assert_eq!(err.to_string(), "invalid input: worksheet 1 divides by zero or overflows");
+
+        let worksheets = Solution::parse("9223372036854775807\n1\n+\n").unwrap();
+        let err = Solution::part_one(&worksheets).err().unwrap();
+        assert_eq!(err.to_string(), "invalid input: worksheet 1 overflows");
+    }
+
     #[test]
     fn solve_part1_example() {
-        let worksheets = Solution::read_level("./input/day_06_example.txt");
+        let worksheets = Solution::parse(Solution::EXAMPLE).unwrap();
         let worksheets_results_sum: i64 = worksheets
             .iter()
-            .map(|ws| ws.compute(ComputeMode::Horizontal))
+            .map(|ws| ws.compute(ComputeMode::Horizontal).unwrap())
             .sum();
         assert_eq!(worksheets_results_sum, 4277556);
     }
 
     #[test]
     fn solve_part1() {
-        let worksheets = Solution::read_level("./input/day_06.txt");
+        require_input!("./input/day_06.txt");
+
+        let worksheets = Solution::read_level("./input/day_06.txt").unwrap();
         let worksheets_results_sum: i64 = worksheets
             .iter()
-            .map(|ws| ws.compute(ComputeMode::Horizontal))
+            .map(|ws| ws.compute(ComputeMode::Horizontal).unwrap())
             .sum();
         assert_eq!(worksheets_results_sum, 4951502530386);
     }
 
     #[test]
     fn solve_part2_example() {
-        let worksheets = Solution::read_level("./input/day_06_example.txt");
+        let worksheets = Solution::parse(Solution::EXAMPLE).unwrap();
         let worksheets_results_sum: i64 = worksheets
             .iter()
-            .map(|ws| ws.compute(ComputeMode::Vertical))
+            .map(|ws| ws.compute(ComputeMode::Vertical).unwrap())
             .sum();
         assert_eq!(worksheets_results_sum, 3263827);
     }
 
     #[test]
     fn solve_part2() {
-        let worksheets = Solution::read_level("./input/day_06.txt");
+        require_input!("./input/day_06.txt");
+
+        let worksheets = Solution::read_level("./input/day_06.txt").unwrap();
         let worksheets_results_sum: i64 = worksheets
             .iter()
-            .map(|ws| ws.compute(ComputeMode::Vertical))
+            .map(|ws| ws.compute(ComputeMode::Vertical).unwrap())
             .sum();
         assert_eq!(worksheets_results_sum, 8486156119946);
     }
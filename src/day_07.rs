@@ -1,66 +1,57 @@
-use std::collections::HashMap;
-use std::fs::File;
-use std::io::{ BufRead, BufReader };
-
-#[derive(PartialEq, Eq, Hash, Copy, Clone)]
-struct Position {
-    x: i32,
-    y: i32,
+use crate::grid::Grid;
+use crate::{ AocError, AocResult, Solver };
+
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum Cell {
+    Empty,
+    Start,
+    Splitter,
 }
 
-struct SplitterMap {
-    start: Position,
-    splitters: Vec<Position>,
-    beams: Vec<Position>,
-    beams_count: HashMap<Position, i64>,
+pub struct SplitterMap {
+    grid: Grid<Cell>,
+    start: (usize, usize),
     splits: i32,
-    worlds: i32,
-    map_size: Position,
 }
 
 impl SplitterMap {
-    pub fn draw(&self) {
-        for y in 0..self.map_size.y {
-            for x in 0..self.map_size.x {
-                if self.start.x == x && self.start.y == y {
-                    print!("S");
-                } else if self.splitters.iter().any(|s| s.x == x && s.y == y) {
-                    print!("^");
-                } else if self.beams_count.contains_key(&(Position { x, y })) {
-                    let count = self.beams_count[&(Position { x, y })];
-                    if count > 15 {
-                        print!("X");
-                    } else if count > 9 {
-                        print!("{}", (b'A' + ((count - 10) as u8)) as char);
-                    } else {
-                        print!("{}", count);
-                    }
-                } else if self.beams.iter().any(|b| b.x == x && b.y == y) {
-                    print!("|");
-                } else {
-                    print!(".");
-                }
+    pub fn render(&self) -> String {
+        self.grid.render(|cell| {
+            match cell {
+                Cell::Empty => '.',
+                Cell::Start => 'S',
+                Cell::Splitter => '^',
             }
-            println!();
-        }
+        })
+    }
+
+    pub fn draw(&self) {
+        debug!("map:\n{}", self.render());
+    }
+
+    pub fn shot_beam(&mut self) -> i64 {
+        let (splits, result) = self.simulate();
+        self.splits = splits;
+        info!("timelines: {}", result);
+        result
     }
 
-    pub fn shot_beam(&mut self) {
-        let mut beams = vec![0i64; self.map_size.x as usize];
-        beams[self.start.x as usize] = 1;
+    fn simulate(&self) -> (i32, i64) {
+        let mut splits = 0;
+        let mut beams = vec![0i64; self.grid.width()];
+        beams[self.start.0] = 1;
 
-        for y in 1..self.map_size.y {
+        for y in 1..self.grid.height() {
             let old = beams.clone();
-            beams = vec![0i64; self.map_size.x as usize];
+            beams = vec![0i64; self.grid.width()];
 
             for (x, &count) in old.iter().enumerate() {
                 if count == 0 {
                     continue;
                 }
 
-                let is_splitter = self.splitters.iter().any(|s| s.x == (x as i32) && s.y == y);
-
-                if is_splitter {
+                if self.grid.get(x, y) == Some(&Cell::Splitter) {
+                    splits += 1;
                     if x > 0 {
                         beams[x - 1] += count;
                     }
@@ -73,65 +64,60 @@ impl SplitterMap {
             }
         }
 
-        let result: i64 = beams.iter().sum();
-        println!("P2 Result: {}", result);
+        (splits, beams.iter().sum())
     }
 }
 
-struct Solution;
+pub struct Solution;
 
 impl Solution {
-    pub fn build_map(file_name: &str) -> SplitterMap {
-        let file = File::open(file_name).unwrap();
-        let reader = BufReader::new(file);
-        let lines: Vec<String> = reader
-            .lines()
-            .map(|l| l.unwrap())
-            .collect();
+    pub fn build_map(file_name: &str) -> AocResult<SplitterMap> {
+        Solution::parse_file(file_name)
+    }
+}
 
-        let mut map = SplitterMap {
-            start: Position { x: 0, y: 0 },
-            splitters: Vec::new(),
-            beams: Vec::new(),
-            beams_count: HashMap::new(),
-            splits: 0,
-            worlds: 0,
-            map_size: Position {
-                x: lines[0].len() as i32,
-                y: lines.len() as i32,
-            },
-        };
-        for (y, line) in lines.iter().enumerate() {
-            for (x, ch) in line.chars().enumerate() {
-                match ch {
-                    'S' => {
-                        map.start = Position {
-                            x: x as i32,
-                            y: y as i32,
-                        };
-                        map.beams.push(Position {
-                            x: x as i32,
-                            y: y as i32,
-                        });
-                    }
-                    '^' => {
-                        map.splitters.push(Position {
-                            x: x as i32,
-                            y: y as i32,
-                        });
-                    }
-                    _ => {}
-                }
+impl Solver for Solution {
+    type Input = SplitterMap;
+    type Answer1 = i32;
+    type Answer2 = i64;
+
+    const TITLE: &'static str = "Laboratories";
+    const EXAMPLE: &'static str = include_str!("../input/day_07_example.txt");
+
+    fn parse(input: &str) -> AocResult<SplitterMap> {
+        if input.lines().next().is_none() {
+            return Err(AocError::invalid("expected a map, got an empty input"));
+        }
+        let grid = Grid::parse(input, |c| {
+            match c {
+                '.' => Some(Cell::Empty),
+                'S' => Some(Cell::Start),
+                '^' => Some(Cell::Splitter),
+                _ => None,
             }
+        })?;
+
+        let starts = grid.find_all(|&cell| cell == Cell::Start);
+        match starts[..] {
+            [] => Err(AocError::invalid("map has no start position `S`")),
+            [start] => Ok(SplitterMap { grid, start, splits: 0 }),
+            [_, (x, y), ..] => Err(AocError::parse(y + 1, x + 1, "second start position `S`")),
         }
+    }
+
+    fn part_one(map: &SplitterMap) -> i32 {
+        map.simulate().0
+    }
 
-        map
+    fn part_two(map: &SplitterMap) -> i64 {
+        map.simulate().1
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::answers;
 
     // #[test]
     // fn test_map_draw() {
@@ -156,15 +142,49 @@ mod tests {
     //     // assert!(splitter_map.splits == 1594);
     // }
 
+    #[test]
+    fn test_solve_part_1_example() {
+        let splitter_map = Solution::parse(Solution::EXAMPLE).unwrap();
+        assert_eq!(Solution::part_one(&splitter_map), 21);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Solution::parse("..S..\n..^..\n.^.\n").err().unwrap();
+        assert_eq!(err.to_string(), "invalid input at line 3: row has width 3, expected 5");
+
+        let err = Solution::parse("..S..\n..#..\n").err().unwrap();
+        assert_eq!(err.to_string(), "parse error at line 2, column 3: unexpected cell `#`");
+
+        assert!(Solution::parse(".....\n..^..\n").is_err());
+
+        let err = Solution::parse("..S..\n.S^..\n").err().unwrap();
+        assert_eq!(err.to_string(), "parse error at line 2, column 2: second start position `S`");
+        assert!(Solution::parse("").is_err());
+    }
+
+    #[test]
+    fn test_render_round_trip() {
+        let splitter_map = Solution::parse(Solution::EXAMPLE).unwrap();
+        let example: String = Solution::EXAMPLE
+            .lines()
+            .map(|line| format!("{}\n", line))
+            .collect();
+        assert_eq!(splitter_map.render(), example);
+        assert_eq!(splitter_map.start, (7, 0));
+    }
+
     #[test]
     fn test_solve_part_2_example() {
-        let mut splitter_map = Solution::build_map("input/day_07_example.txt");
-        splitter_map.shot_beam();
+        let mut splitter_map = Solution::parse(Solution::EXAMPLE).unwrap();
+        assert_eq!(splitter_map.shot_beam(), 40);
     }
 
     #[test]
     fn test_solve_part_2() {
-        let mut splitter_map = Solution::build_map("input/day_07.txt");
-        splitter_map.shot_beam();
+        require_input!("input/day_07.txt");
+
+        let mut splitter_map = Solution::build_map("input/day_07.txt").unwrap();
+        answers::assert_recorded(7, 2, splitter_map.shot_beam());
     }
 }
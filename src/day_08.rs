@@ -1,22 +1,11 @@
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{ BufRead, BufReader };
-
-#[derive(Hash, Eq, PartialEq, Clone)]
-struct Position {
-    x: i32,
-    y: i32,
-    z: i32,
-}
 
-impl Position {
-    pub fn distance(&self, other: &Position) -> f64 {
-        let dx = (self.x - other.x) as f64;
-        let dy = (self.y - other.y) as f64;
-        let dz = (self.z - other.z) as f64;
-        (dx * dx + dy * dy + dz * dz).sqrt()
-    }
-}
+use crate::geometry::Point3;
+use crate::log::{ self, Level };
+use crate::parse;
+use crate::{ AocError, AocResult, Solver };
+
+pub type Position = Point3<i64>;
 
 struct UnionFind {
     parent: Vec<usize>,
@@ -76,61 +65,67 @@ impl UnionFind {
     }
 }
 
-struct Solution;
+// Keeps squared distances, three squared differences of up to 1e9 each, inside an i64
+const MAX_COORD: i64 = 500_000_000;
 
-#[allow(dead_code)]
-impl Solution {
-    pub fn read_input(file_name: &str) -> Vec<Position> {
-        let file = File::open(file_name).unwrap();
-        let reader = BufReader::new(file);
-        let lines: Vec<String> = reader
-            .lines()
-            .map(|l| l.unwrap())
-            .collect();
+// The example connects 10 pairs, the real input 1000
+const EXAMPLE_SIZE: usize = 20;
 
-        let mut vecs: Vec<Position> = Vec::new();
+// Connection count for inputs that do not name one on a `connections: N` first line
+pub fn default_connections(boxes: usize) -> usize {
+    if boxes <= EXAMPLE_SIZE { 10 } else { 1000 }
+}
 
-        for line in lines {
-            let coords: Vec<i32> = line
-                .split(',')
-                .map(|s| s.parse::<i32>().unwrap())
-                .collect();
+pub struct Playground {
+    pub boxes: Vec<Position>,
+    pub connections: usize,
+}
 
-            vecs.push(Position { x: coords[0], y: coords[1], z: coords[2] });
-        }
+pub struct Solution;
 
-        vecs
+#[allow(dead_code)]
+impl Solution {
+    pub fn read_input(file_name: &str) -> AocResult<Playground> {
+        Solution::parse_file(file_name)
     }
 
-    pub fn solve(positions: &Vec<Position>, num_connections: usize) -> usize {
+    fn sorted_edges(positions: &[Position]) -> Vec<(i64, usize, usize)> {
         let n = positions.len();
 
-        let mut edges: Vec<(f64, usize, usize)> = Vec::new();
+        let mut edges: Vec<(i64, usize, usize)> = Vec::new();
         for i in 0..n {
             for j in i + 1..n {
-                let dist_sq = positions[i].distance(&positions[j]);
+                let dist_sq = positions[i].distance_sq(&positions[j]);
                 edges.push((dist_sq, i, j));
             }
         }
 
-        edges.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        edges.sort_by_key(|edge| edge.0);
+        edges
+    }
+
+    pub fn solve(positions: &[Position], num_connections: usize) -> usize {
+        let n = positions.len();
+        let edges = Solution::sorted_edges(positions);
+
         let mut uf = UnionFind::new(n);
-        let mut attempts = 0;
-        for &(_, i, j) in &edges {
-            if attempts >= num_connections {
-                break;
-            }
+        for &(_, i, j) in edges.iter().take(num_connections) {
             uf.union(i, j);
-            attempts += 1;
         }
 
         let circuit_sizes = uf.get_circuit_sizes();
 
-        for &(_, i, j) in &edges {
-            if uf.union(i, j) {
-                if uf.num_circuits() == 1 {
-                    println!(
-                        "Final connection: ({}, {}, {}) to ({}, {}, {})",
+        // Only for the log, keeps joining boxes until they form a single circuit
+        if log::enabled(Level::Debug) {
+            let mut circuits = circuit_sizes.len();
+            for &(_, i, j) in edges.iter().skip(num_connections) {
+                if !uf.union(i, j) {
+                    continue;
+                }
+                circuits -= 1;
+                if circuits == 1 {
+                    debug!(
+                        "final connection: ({}, {}, {}) to ({}, {}, {})",
                         positions[i].x,
                         positions[i].y,
                         positions[i].z,
@@ -138,36 +133,171 @@ impl Solution {
                         positions[j].y,
                         positions[j].z
                     );
-                    println!("Result: {}", positions[i].x * positions[j].x);
+                    debug!("product of x: {}", positions[i].x * positions[j].x);
+                    break;
                 }
             }
         }
 
-        circuit_sizes[0] * circuit_sizes[1] * circuit_sizes[2]
+        // Small inputs can end up with fewer than three circuits
+        circuit_sizes.iter().take(3).product()
+    }
+
+    fn final_connection(positions: &[Position]) -> i64 {
+        let edges = Solution::sorted_edges(positions);
+        let mut uf = UnionFind::new(positions.len());
+        let mut circuits = positions.len();
+
+        for &(_, i, j) in &edges {
+            if uf.union(i, j) {
+                circuits -= 1;
+                if circuits == 1 {
+                    return positions[i].x * positions[j].x;
+                }
+            }
+        }
+
+        0
+    }
+}
+
+impl Solver for Solution {
+    type Input = Playground;
+    type Answer1 = usize;
+    type Answer2 = i64;
+
+    const TITLE: &'static str = "Playground";
+    const EXAMPLE: &'static str = include_str!("../input/day_08_example.txt");
+
+    fn parse(input: &str) -> AocResult<Playground> {
+        let mut vecs: Vec<Position> = Vec::new();
+        let mut connections = None;
+
+        for (idx, line) in input.lines().enumerate() {
+            if let Some(count) = line.strip_prefix("connections: ").filter(|_| idx == 0) {
+                let count = parse::value(line, count, "connection count");
+                connections = Some(count.map_err(|e| e.at_line(1))?);
+                continue;
+            }
+            let [x, y, z] = parse::tuple(line, ',', "coordinate").map_err(|e| e.at_line(idx + 1))?;
+            if let Some(c) = [x, y, z].into_iter().find(|c: &i64| c.abs() > MAX_COORD) {
+                let message = format!("coordinate {} is beyond ±{}", c, MAX_COORD);
+                return Err(AocError::invalid_at(idx + 1, message));
+            }
+            vecs.push(Point3::new(x, y, z));
+        }
+
+        let connections = connections.unwrap_or(default_connections(vecs.len()));
+        Ok(Playground { boxes: vecs, connections })
+    }
+
+    fn part_one(playground: &Playground) -> usize {
+        Solution::solve(&playground.boxes, playground.connections)
+    }
+
+    fn part_two(playground: &Playground) -> i64 {
+        Solution::final_connection(&playground.boxes)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Solution;
+    use super::{ Solution, UnionFind };
+    use crate::property;
+    use crate::Solver;
 
     #[test]
     fn read_example_input() {
-        let positions = Solution::read_input("./input/day_08_example.txt");
-        assert!(positions.len() == 20);
+        let playground = Solution::parse(Solution::EXAMPLE).unwrap();
+        assert!(playground.boxes.len() == 20);
+        assert_eq!(playground.connections, 10);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = Solution::parse("1,2,3\n4,x,6\n").err().unwrap();
+        assert_eq!(err.to_string(), "parse error at line 2, column 3: invalid coordinate `x`");
+
+        let err = Solution::parse("1,2,3\n4,5\n").err().unwrap();
+        assert_eq!(err.to_string(), "invalid input at line 2: expected 3 coordinates, got 2");
+
+        let err = Solution::parse("0,0,0\n3037000500,3037000500,3037000500\n").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "invalid input at line 2: coordinate 3037000500 is beyond ±500000000"
+        );
+
+        let err = Solution::parse("connections: ten\n1,2,3\n").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "parse error at line 1, column 14: invalid connection count `ten`"
+        );
     }
 
     #[test]
     fn solve_example() {
-        let positions = Solution::read_input("./input/day_08_example.txt");
-        let res = Solution::solve(&positions, 10);
+        let playground = Solution::parse(Solution::EXAMPLE).unwrap();
+        let res = Solution::solve(&playground.boxes, 10);
         assert_eq!(res, 40);
     }
 
+    #[test]
+    fn solve_with_fewer_than_three_circuits() {
+        let playground = Solution::parse("0,0,0\n1,1,1\n").unwrap();
+        assert_eq!(Solution::solve(&playground.boxes, 10), 2);
+        assert_eq!(Solution::solve(&[], 10), 1);
+    }
+
+    #[test]
+    fn solve_part2_example() {
+        let playground = Solution::parse(Solution::EXAMPLE).unwrap();
+        assert_eq!(Solution::part_two(&playground), 25272);
+    }
+
+    #[test]
+    fn connections_named_in_input() {
+        let input = format!("connections: 1\n{}", Solution::EXAMPLE);
+        let playground = Solution::parse(&input).unwrap();
+        assert_eq!(playground.connections, 1);
+        // One wire joins two boxes, the other 18 stay on their own
+        assert_eq!(Solution::part_one(&playground), 2);
+    }
+
     #[test]
     fn solve() {
-        let positions = Solution::read_input("./input/day_08.txt");
-        let res = Solution::solve(&positions, 1000);
+        require_input!("./input/day_08.txt");
+
+        let playground = Solution::read_input("./input/day_08.txt").unwrap();
+        let res = Solution::solve(&playground.boxes, 1000);
         assert_eq!(res, 102816);
     }
+
+    #[test]
+    fn circuit_sizes_add_up() {
+        property::check(
+            |rng| {
+                let n = rng.range(0, 30) as usize;
+                let unions = (0..rng.range(0, 40))
+                    .map(|_| (rng.range(0, 29) as usize, rng.range(0, 29) as usize))
+                    .collect::<Vec<_>>();
+                (n, unions)
+            },
+            |(n, unions)| {
+                // Never empty, and every union joins boxes that exist
+                let n = n + 1;
+                let mut uf = UnionFind::new(n);
+                for &(a, b) in unions {
+                    uf.union(a % n, b % n);
+                }
+
+                let sizes = uf.get_circuit_sizes();
+                let total: usize = sizes.iter().sum();
+                ensure!(total == n, "sizes {:?} add up to {}, not {}", sizes, total, n);
+                ensure!(sizes.windows(2).all(|w| w[0] >= w[1]), "sizes {:?} not sorted", sizes);
+                let circuits = uf.num_circuits();
+                ensure!(sizes.len() == circuits, "{} sizes for {} circuits", sizes.len(), circuits);
+                Ok(())
+            }
+        );
+    }
 }
@@ -1,128 +1,98 @@
-use std::fs::File;
-use std::io::{ BufRead, BufReader };
-
-#[derive(Clone)]
-struct Position {
-    x: i64,
-    y: i64,
+use crate::geometry::{ Point2, Rect };
+use crate::grid::Grid;
+use crate::parse;
+use crate::{ AocError, AocResult, Solver };
+
+pub type Position = Point2<i64>;
+
+// Rectangles up to 2e9 + 1 tiles across still have an area that fits an i64
+const MAX_COORD: i64 = 1_000_000_000;
+
+// Tiles squashed onto a grid: every corner x gets a column of its own and each run of tiles
+// between two corners shares one, same for rows. A cell is then wholly inside or outside.
+struct TileMap {
+    xs: Vec<i64>,
+    ys: Vec<i64>,
+    // Outside cells in the rectangle from (0, 0) up to but excluding (x, y)
+    outside: Grid<u32>,
 }
 
-// Part 2 is from reddit - I gave up
-fn build_polygon_segments(tiles: &[Position]) -> Vec<((i64, i64), (i64, i64))> {
-    (0..tiles.len())
-        .map(|i| {
-            let curr = &tiles[i];
-            let next = &tiles[(i + 1) % tiles.len()];
-            ((curr.x, curr.y), (next.x, next.y))
-        })
-        .collect()
+// Starts of the ranges of tiles that share a cell, padded so the map has an outside border
+fn breaks(values: impl Iterator<Item = i64>) -> Vec<i64> {
+    let mut breaks: Vec<i64> = values.flat_map(|v| [v - 1, v, v + 1]).collect();
+    breaks.sort_unstable();
+    breaks.dedup();
+    breaks
 }
 
-fn is_rectangle_inside_polygon(
-    x1: i64,
-    y1: i64,
-    x2: i64,
-    y2: i64,
-    segments: &[((i64, i64), (i64, i64))]
-) -> bool {
-    let (min_x, max_x) = (x1.min(x2), x1.max(x2));
-    let (min_y, max_y) = (y1.min(y2), y1.max(y2));
-
-    // Check if any polygon edge cuts through the rectangle
-    for &((sx1, sy1), (sx2, sy2)) in segments {
-        if sx1 == sx2 {
-            // Vertical edge
-            if sx1 > min_x && sx1 < max_x {
-                let seg_y_min = sy1.min(sy2);
-                let seg_y_max = sy1.max(sy2);
-                if min_y.max(seg_y_min) < max_y.min(seg_y_max) {
-                    return false;
+impl TileMap {
+    fn new(tiles: &[Position]) -> Self {
+        let xs = breaks(tiles.iter().map(|t| t.x));
+        let ys = breaks(tiles.iter().map(|t| t.y));
+        let (width, height) = (xs.len(), ys.len());
+
+        let mut boundary = Grid::new(width, height, false);
+        for (i, a) in tiles.iter().enumerate() {
+            let b = tiles[(i + 1) % tiles.len()];
+            let (x1, x2) = (index(&xs, a.x.min(b.x)), index(&xs, a.x.max(b.x)));
+            let (y1, y2) = (index(&ys, a.y.min(b.y)), index(&ys, a.y.max(b.y)));
+            for y in y1..=y2 {
+                for x in x1..=x2 {
+                    boundary.set(x, y, true);
                 }
             }
-        } else {
-            // Horizontal edge
-            if sy1 > min_y && sy1 < max_y {
-                let seg_x_min = sx1.min(sx2);
-                let seg_x_max = sx1.max(sx2);
-                if min_x.max(seg_x_min) < max_x.min(seg_x_max) {
-                    return false;
+        }
+
+        // Everything the border reaches without crossing the loop is outside
+        let mut outside = Grid::new(width, height, false);
+        let mut stack = vec![(0, 0)];
+        outside.set(0, 0, true);
+        while let Some((x, y)) = stack.pop() {
+            for (nx, ny) in boundary.neighbours4(x, y) {
+                if !boundary.get(nx, ny).unwrap() && !outside.get(nx, ny).unwrap() {
+                    outside.set(nx, ny, true);
+                    stack.push((nx, ny));
                 }
             }
         }
-    }
-
-    // Use ray casting to check if rectangle center is inside polygon
-    let center_x = ((min_x + max_x) as f64) / 2.0;
-    let center_y = ((min_y + max_y) as f64) / 2.0;
 
-    let mut intersections = 0;
-    for &((sx1, sy1), (sx2, sy2)) in segments {
-        if sx1 == sx2 {
-            let edge_x = sx1 as f64;
-            if edge_x > center_x {
-                let edge_y_min = sy1.min(sy2) as f64;
-                let edge_y_max = sy1.max(sy2) as f64;
-                if center_y > edge_y_min && center_y < edge_y_max {
-                    intersections += 1;
-                }
+        let mut counts = Grid::new(width + 1, height + 1, 0);
+        for y in 0..height {
+            for x in 0..width {
+                let count = (*outside.get(x, y).unwrap() as u32) +
+                    counts.get(x, y + 1).unwrap() +
+                    counts.get(x + 1, y).unwrap() -
+                    counts.get(x, y).unwrap();
+                counts.set(x + 1, y + 1, count);
             }
         }
+        TileMap { xs, ys, outside: counts }
     }
 
-    intersections % 2 == 1
-}
-
-fn dist(p1: &Position, p2: &Position) -> i64 {
-    (p1.x - p2.x).abs() + (p1.y - p2.y).abs()
+    fn is_inside(&self, rect: &Rect<i64>) -> bool {
+        let (x1, x2) = (index(&self.xs, rect.min.x), index(&self.xs, rect.max.x) + 1);
+        let (y1, y2) = (index(&self.ys, rect.min.y), index(&self.ys, rect.max.y) + 1);
+        let count = |x, y| *self.outside.get(x, y).unwrap();
+        count(x2, y2) + count(x1, y1) == count(x1, y2) + count(x2, y1)
+    }
 }
 
-fn calculate_square_size(pos1: &Position, pos2: &Position) -> usize {
-    let width = (pos2.x - pos1.x).abs() + 1;
-    let height = (pos2.y - pos1.y).abs() + 1;
-    (width * height) as usize
+fn index(breaks: &[i64], value: i64) -> usize {
+    breaks.binary_search(&value).unwrap()
 }
 
-struct Solution;
+pub struct Solution;
 
 impl Solution {
-    pub fn read_input(file_name: &str) -> Vec<Position> {
-        let file = File::open(file_name).unwrap();
-        let reader = BufReader::new(file);
-        let lines: Vec<String> = reader
-            .lines()
-            .map(|l| l.unwrap())
-            .collect();
-
-        let mut positions = Vec::new();
-        for line in &lines {
-            let cords = line.split_once(',').unwrap();
-            let x = cords.0.parse::<i64>().unwrap();
-            let y = cords.1.parse::<i64>().unwrap();
-            positions.push(Position { x, y });
-        }
-
-        positions.reverse();
-        positions
+    pub fn read_input(file_name: &str) -> AocResult<Vec<Position>> {
+        Solution::parse_file(file_name)
     }
 
-    pub fn find_biggest_area(_positions: &Vec<Position>) -> usize {
-        let mut all_pairs: Vec<(usize, usize, usize)> = Vec::new();
-        for i in 0.._positions.len() {
-            for j in 0.._positions.len() {
-                if i != j {
-                    all_pairs.push((dist(&_positions[i], &_positions[j]) as usize, i, j));
-                }
-            }
-        }
-
-        all_pairs.sort_by(|a, b| b.0.cmp(&a.0));
-        all_pairs.truncate(1);
-
+    pub fn find_biggest_area(positions: &[Position]) -> usize {
         let mut biggest = 0;
-        for pair in all_pairs {
-            let size = calculate_square_size(&_positions[pair.1], &_positions[pair.2]);
-            if size > biggest {
-                biggest = size;
+        for (i, a) in positions.iter().enumerate() {
+            for b in &positions[i + 1..] {
+                biggest = biggest.max(Rect::from_corners(*a, *b).area() as usize);
             }
         }
         biggest
@@ -134,21 +104,15 @@ impl Solution {
         }
 
         let mut max_area = 0;
-        let segments = build_polygon_segments(&tiles);
+        let map = TileMap::new(&tiles);
 
         for i in 0..tiles.len() {
-            let (x1, y1) = (tiles[i].x, tiles[i].y);
-            for j in i + 1..tiles.len() {
-                let (x2, y2) = (tiles[j].x, tiles[j].y);
-
-                if x1 != x2 && y1 != y2 {
-                    let width = (x2 - x1).abs() + 1;
-                    let height = (y2 - y1).abs() + 1;
-                    let area = (width * height) as usize;
-
-                    if area > max_area && is_rectangle_inside_polygon(x1, y1, x2, y2, &segments) {
-                        max_area = area;
-                    }
+            for tile in &tiles[i + 1..] {
+                let rect = Rect::from_corners(tiles[i], *tile);
+                let area = rect.area() as usize;
+
+                if area > max_area && map.is_inside(&rect) {
+                    max_area = area;
                 }
             }
         }
@@ -157,50 +121,127 @@ impl Solution {
     }
 }
 
+impl Solver for Solution {
+    type Input = Vec<Position>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const TITLE: &'static str = "Movie Theater";
+    const EXAMPLE: &'static str = include_str!("../input/day_09_example.txt");
+
+    fn parse(input: &str) -> AocResult<Vec<Position>> {
+        let mut positions = Vec::new();
+        for (idx, line) in input.lines().enumerate() {
+            let (x, y) = parse::pair(line, ',', "x,y", "coordinate").map_err(|e| {
+                e.at_line(idx + 1)
+            })?;
+            if let Some(c) = [x, y].into_iter().find(|c: &i64| c.abs() > MAX_COORD) {
+                let message = format!("coordinate {} is beyond ±{}", c, MAX_COORD);
+                return Err(AocError::invalid_at(idx + 1, message));
+            }
+            positions.push(Point2::new(x, y));
+        }
+
+        positions.reverse();
+        Ok(positions)
+    }
+
+    fn part_one(positions: &Vec<Position>) -> usize {
+        Solution::find_biggest_area(positions)
+    }
+
+    fn part_two(positions: &Vec<Position>) -> usize {
+        Solution::find_largest_valid_rectangle(positions.clone())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::answers;
 
     #[test]
     fn test_square_size_calc() {
-        let pos1 = Position { x: 1, y: 1 };
-        let pos2 = Position { x: 2, y: 2 };
-        let size = calculate_square_size(&pos1, &pos2);
-        println!("Calculated size: {}", size);
+        let pos1 = Point2::new(1, 1);
+        let pos2 = Point2::new(2, 2);
+        let size = Rect::from_corners(pos1, pos2).area();
         assert_eq!(size, 4);
     }
 
     #[test]
     fn test_biggest_area() {
-        let positions = Solution::read_input("./input/day_09_example.txt");
+        let positions = Solution::parse(Solution::EXAMPLE).unwrap();
         let area = Solution::find_biggest_area(&positions);
         assert_eq!(area, 50);
     }
 
+    #[test]
+    fn biggest_area_is_not_the_farthest_pair() {
+        // (4,9)-(7,0) and (3,8)-(7,0) are equally far apart, only the second makes 5 * 9
+        let positions = Solution::parse("3,0\n3,8\n4,8\n4,9\n5,9\n5,4\n7,4\n7,0\n").unwrap();
+        assert_eq!(Solution::find_biggest_area(&positions), 45);
+    }
+
+    #[test]
+    fn rectangle_centre_level_with_a_vertex() {
+        let positions = Solution::parse("1,0\n1,6\n3,6\n3,3\n5,3\n5,0\n").unwrap();
+        assert_eq!(Solution::part_two(&positions), 21);
+    }
+
+    #[test]
+    fn edges_on_neighbouring_tiles() {
+        // The slot between x = 2 and x = 3 is only edges, so the whole 6x6 square is covered
+        let positions = Solution::parse("0,0\n0,5\n2,5\n2,1\n3,1\n3,5\n5,5\n5,0\n").unwrap();
+        assert_eq!(Solution::part_two(&positions), 36);
+    }
+
     #[test]
     fn test_input_read() {
-        let positions = Solution::read_input("./input/day_09_example.txt");
+        let positions = Solution::parse(Solution::EXAMPLE).unwrap();
         assert_eq!(positions.len(), 8);
     }
 
+    #[test]
+    fn test_parse_errors() {
+        let err = Solution::parse("7,1\n11\n").err().unwrap();
+        assert_eq!(err.to_string(), "parse error at line 2, column 1: expected `x,y`, got `11`");
+
+        let err = Solution::parse("7,1\n11,y\n").err().unwrap();
+        assert_eq!(err.to_string(), "parse error at line 2, column 4: invalid coordinate `y`");
+
+        let err = Solution::parse("0,0\n0,4000000000\n").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "invalid input at line 2: coordinate 4000000000 is beyond ±1000000000"
+        );
+        let corners = "-1000000000,-1000000000\n-1000000000,1000000000\n\
+                       1000000000,1000000000\n1000000000,-1000000000\n";
+        let positions = Solution::parse(corners).unwrap();
+        assert_eq!(Solution::part_one(&positions), 2000000001 * 2000000001);
+    }
+
     #[test]
     fn test_part_1_solution() {
-        let positions = Solution::read_input("./input/day_09.txt");
+        require_input!("./input/day_09.txt");
+
+        let positions = Solution::read_input("./input/day_09.txt").unwrap();
         let area = Solution::find_biggest_area(&positions);
         assert_eq!(area, 4755429952);
     }
 
     #[test]
     fn find_biggest_area_inside_example() {
-        let positions = Solution::read_input("./input/day_09_example.txt");
+        let positions = Solution::parse(Solution::EXAMPLE).unwrap();
         let area = Solution::find_largest_valid_rectangle(positions);
         assert_eq!(area, 24);
     }
 
     #[test]
     fn test_part_2_solution() {
-        let positions = Solution::read_input("./input/day_09.txt");
+        require_input!("./input/day_09.txt");
+
+        let positions = Solution::read_input("./input/day_09.txt").unwrap();
         let area = Solution::find_largest_valid_rectangle(positions);
-        println!("Part 2 solution: {}", area);
+        answers::assert_recorded(9, 2, area);
     }
 }
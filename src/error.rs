@@ -0,0 +1,146 @@
+use std::fmt;
+use std::io;
+
+#[derive(Debug)]
+pub enum AocError {
+    Io(io::Error),
+    // Line and column are 1-based
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    Invalid {
+        line: Option<usize>,
+        message: String,
+    },
+    Http {
+        status: u16,
+        message: String,
+    },
+    Config(String),
+}
+
+pub type AocResult<T> = Result<T, AocError>;
+
+impl AocError {
+    pub fn parse(line: usize, column: usize, message: impl Into<String>) -> Self {
+        AocError::Parse {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    pub fn invalid(message: impl Into<String>) -> Self {
+        AocError::Invalid {
+            line: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn invalid_at(line: usize, message: impl Into<String>) -> Self {
+        AocError::Invalid {
+            line: Some(line),
+            message: message.into(),
+        }
+    }
+
+    // Line parsers report line 1, callers move the error to the real line
+    pub fn at_line(self, line: usize) -> Self {
+        match self {
+            AocError::Parse { column, message, .. } => AocError::Parse { line, column, message },
+            AocError::Invalid { message, .. } => AocError::Invalid { line: Some(line), message },
+            other => other,
+        }
+    }
+
+    // For errors from a slice that starts `offset` characters into the real line
+    pub fn offset_column(self, offset: usize) -> Self {
+        match self {
+            AocError::Parse { line, column, message } => {
+                AocError::Parse { line, column: column + offset, message }
+            }
+            other => other,
+        }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AocError::Io(e) => write!(f, "I/O error: {}", e),
+            AocError::Parse { line, column, message } => {
+                write!(f, "parse error at line {}, column {}: {}", line, column, message)
+            }
+            AocError::Invalid { line: Some(line), message } => {
+                write!(f, "invalid input at line {}: {}", line, message)
+            }
+            AocError::Invalid { line: None, message } => write!(f, "invalid input: {}", message),
+            AocError::Http { status, message } => write!(f, "HTTP {}: {}", status, message),
+            AocError::Config(message) => write!(f, "configuration error: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AocError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for AocError {
+    fn from(e: io::Error) -> Self {
+        AocError::Io(e)
+    }
+}
+
+// 1-based column of `part` inside `line`, `part` must be a subslice of `line`
+pub fn column_of(line: &str, part: &str) -> usize {
+    let offset = (part.as_ptr() as usize).saturating_sub(line.as_ptr() as usize);
+    line[..offset.min(line.len())].chars().count() + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_parse_error() {
+        let err = AocError::parse(3, 7, "expected a number");
+        assert_eq!(err.to_string(), "parse error at line 3, column 7: expected a number");
+    }
+
+    #[test]
+    fn move_error_to_line() {
+        let err = AocError::parse(1, 2, "bad").at_line(12);
+        assert_eq!(err.to_string(), "parse error at line 12, column 2: bad");
+
+        let err = AocError::invalid("empty range").at_line(4);
+        assert_eq!(err.to_string(), "invalid input at line 4: empty range");
+
+        let err = AocError::parse(1, 2, "bad").offset_column(6);
+        assert_eq!(err.to_string(), "parse error at line 1, column 8: bad");
+    }
+
+    #[test]
+    fn display_http_and_config_errors() {
+        let err = AocError::Http { status: 404, message: "Not Found".to_string() };
+        assert_eq!(err.to_string(), "HTTP 404: Not Found");
+
+        let err = AocError::Config("no session token".to_string());
+        assert_eq!(err.to_string(), "configuration error: no session token");
+    }
+
+    #[test]
+    fn column_of_subslice() {
+        let line = "12,-5,7";
+        let parts: Vec<&str> = line.split(',').collect();
+        assert_eq!(column_of(line, parts[0]), 1);
+        assert_eq!(column_of(line, parts[1]), 4);
+        assert_eq!(column_of(line, parts[2]), 7);
+    }
+}
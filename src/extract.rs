@@ -0,0 +1,220 @@
+use std::fs;
+use std::path::{ Path, PathBuf };
+
+use crate::runner;
+use crate::{ AocError, AocResult };
+
+// Example block and answers pulled out of a puzzle page, `answers[0]` belongs to part 1
+#[derive(Debug, PartialEq)]
+pub struct PuzzlePage {
+    pub example: String,
+    pub answers: Vec<String>,
+}
+
+// Each part is an `<article class="day-desc">`. The example is the first `<pre><code>` block
+// introduced by text mentioning "example", the answer is the last `<code><em>` of the part.
+pub fn extract(html: &str) -> AocResult<PuzzlePage> {
+    let articles = sections(html, "<article", "</article>");
+    let first = articles
+        .first()
+        .ok_or(AocError::invalid("puzzle page has no <article>"))?;
+
+    let blocks = sections(first, "<pre><code>", "</code></pre>");
+    let example = blocks
+        .iter()
+        .find(|block| {
+            let offset = block.as_ptr() as usize - first.as_ptr() as usize;
+            let before = &first[..offset];
+            let paragraph = before.rfind("<p>").map_or(before, |idx| &before[idx..]);
+            paragraph.to_lowercase().contains("example")
+        })
+        .or(blocks.first())
+        .ok_or(AocError::invalid("puzzle page has no <pre><code> example"))?;
+
+    let answers = articles
+        .iter()
+        .filter_map(|article| sections(article, "<code><em>", "</em></code>").pop())
+        .map(|answer| decode(answer).trim().to_string())
+        .collect();
+
+    Ok(PuzzlePage {
+        example: decode(example),
+        answers,
+    })
+}
+
+// Inner text of every `open ... close` pair, `open` may be a tag prefix like `<article`
+fn sections<'a>(html: &'a str, open: &str, close: &str) -> Vec<&'a str> {
+    let mut found = Vec::new();
+    let mut rest = html;
+    while let Some(start) = rest.find(open) {
+        let after_open = &rest[start + open.len()..];
+        let content_start = if open.ends_with('>') {
+            0
+        } else {
+            after_open.find('>').map_or(0, |idx| idx + 1)
+        };
+        let Some(end) = after_open.find(close) else {
+            break;
+        };
+        found.push(&after_open[content_start.min(end)..end]);
+        rest = &after_open[end + close.len()..];
+    }
+    found
+}
+
+// Drops nested tags such as `<em>` and resolves the entities AoC pages use
+fn decode(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => {
+                in_tag = true;
+            }
+            '>' if in_tag => {
+                in_tag = false;
+            }
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+const EXPECTED_TODO: &str = "        todo!(\"example answer for part {}\", part)\n";
+
+// Fills in the template's `expected_example` helper, None once it was already edited by hand
+pub fn fill_expected(source: &str, answers: &[String]) -> Option<String> {
+    if !source.contains(EXPECTED_TODO) || answers.iter().any(|a| a.parse::<i64>().is_err()) {
+        return None;
+    }
+
+    let mut body = String::from("        match part {\n");
+    for (idx, answer) in answers.iter().enumerate() {
+        body.push_str(&format!("            {} => {},\n", idx + 1, answer));
+    }
+    body.push_str("            _ => todo!(\"example answer for part {}\", part),\n");
+    body.push_str("        }\n");
+
+    Some(source.replace(EXPECTED_TODO, &body))
+}
+
+// Writes the example file for `day` under `root` unless it already has content, and fills in
+// the day module's expected answers. Returns every file that was written.
+pub fn write_example(
+    root: &Path,
+    day: u8,
+    page: &PuzzlePage,
+    force: bool
+) -> AocResult<Vec<PathBuf>> {
+    let mut written = Vec::new();
+
+    let example_path = root.join(runner::input_file(day, "example").trim_start_matches("./"));
+    let has_content = fs::metadata(&example_path).is_ok_and(|meta| meta.len() > 0);
+    if has_content && !force {
+        return Err(
+            AocError::invalid(
+                format!("{} already has content, use --force to replace it", example_path.display())
+            )
+        );
+    }
+    if let Some(dir) = example_path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(&example_path, &page.example)?;
+    written.push(example_path);
+
+    let module_path = root.join(format!("src/day_{:02}.rs", day));
+    if let Ok(source) = fs::read_to_string(&module_path) {
+        if let Some(source) = fill_expected(&source, &page.answers) {
+            fs::write(&module_path, source)?;
+            written.push(module_path);
+        }
+    }
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scaffold::render_template;
+
+    const PAGE: &str = "<html><body><main>
+<article class=\"day-desc\"><h2>--- Day 3: Lobby ---</h2>
+<p>Batteries are labeled with their <em>joltage</em>:</p>
+<pre><code>1-9</code></pre>
+<p>For example:</p>
+<pre><code>987654321111111
+81111111111111<em>9</em>
+a &lt; b &amp;&amp; c
+</code></pre>
+<p>The total output joltage is <code>98</code> + <code>89</code> = <code><em>357</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>16946</code>.</p>
+<article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2>
+<p>Now the total is <code><em>3121910778619</em></code>.</p>
+</article>
+</main></body></html>";
+
+    #[test]
+    fn extracts_example_and_answers() {
+        let page = extract(PAGE).unwrap();
+        assert_eq!(page.example, "987654321111111\n811111111111119\na < b && c\n");
+        assert_eq!(page.answers, vec!["357".to_string(), "3121910778619".to_string()]);
+    }
+
+    #[test]
+    fn part_one_only() {
+        let html = "<article><p>Here is an example:</p><pre><code>1\n2\n</code></pre>\
+                    <p>Result <code><em>3</em></code>.</p></article>";
+        let page = extract(html).unwrap();
+        assert_eq!(page.example, "1\n2\n");
+        assert_eq!(page.answers, vec!["3".to_string()]);
+
+        assert!(extract("<html>Log in</html>").is_err());
+        assert!(extract("<article><p>No code</p></article>").is_err());
+    }
+
+    #[test]
+    fn fills_template_expectations() {
+        let source = render_template(12);
+        let filled = fill_expected(&source, &["357".to_string()]).unwrap();
+        assert!(
+            filled.contains(
+                "        match part {\n            1 => 357,\n            _ => todo!(\"example"
+            )
+        );
+
+        // Already filled in or not numeric, leave the module alone
+        assert!(fill_expected(&filled, &["1".to_string()]).is_none());
+        assert!(fill_expected(&source, &["abc".to_string()]).is_none());
+    }
+
+    #[test]
+    fn write_example_keeps_existing_file() {
+        let root = std::env::temp_dir().join(format!("aoc2025_extract_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join("src/day_03.rs"), render_template(3)).unwrap();
+        let page = extract(PAGE).unwrap();
+
+        let written = write_example(&root, 3, &page, false).unwrap();
+        assert_eq!(written.len(), 2);
+        let example = fs::read_to_string(root.join("input/day_03_example.txt")).unwrap();
+        assert_eq!(example, page.example);
+        let module = fs::read_to_string(root.join("src/day_03.rs")).unwrap();
+        assert!(module.contains("            2 => 3121910778619,\n"));
+
+        assert!(write_example(&root, 3, &page, false).is_err());
+        assert_eq!(write_example(&root, 3, &page, true).unwrap().len(), 1);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,198 @@
+use std::env;
+use std::fs;
+use std::path::{ Path, PathBuf };
+
+use crate::http::{ self, Request, Response };
+use crate::runner;
+use crate::{ AocError, AocResult };
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u16 = 2025;
+pub const SESSION_ENV: &str = "AOC_SESSION";
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+// Holds the session cookie value, kept out of git like the personal inputs
+pub const SESSION_FILE: &str = ".aoc-session";
+
+const USER_AGENT: &str = concat!(
+    "aoc2025/",
+    env!("CARGO_PKG_VERSION"),
+    " (personal solutions repo; downloads each input once and caches it locally)"
+);
+
+pub struct Client {
+    base_url: String,
+    session: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+        }
+    }
+
+    // Session comes from $AOC_SESSION, falling back to `.aoc-session` under `root`
+    pub fn from_env(root: &Path) -> AocResult<Self> {
+        let session = match env::var(SESSION_ENV) {
+            Ok(session) if !session.trim().is_empty() => session,
+            _ => {
+                fs::read_to_string(root.join(SESSION_FILE)).map_err(|_| {
+                    AocError::Config(
+                        format!("no session token, set {} or create {}", SESSION_ENV, SESSION_FILE)
+                    )
+                })?
+            }
+        };
+        let base_url = env::var(BASE_URL_ENV).unwrap_or(DEFAULT_BASE_URL.to_string());
+        Ok(Client::new(&base_url, &session))
+    }
+
+    pub fn with_base_url(self, base_url: &str) -> Self {
+        Client::new(base_url, &self.session)
+    }
+
+    pub fn day_url(&self, day: u8) -> String {
+        format!("{}/{}/day/{}", self.base_url, YEAR, day)
+    }
+
+    pub fn send(&self, request: Request) -> AocResult<Response> {
+        let request = request
+            .header("User-Agent", USER_AGENT)
+            .header("Cookie", &format!("session={}", self.session));
+        http::send(&request)
+    }
+}
+
+// Downloads the personal input for `day` into `root`, an existing non-empty file is never
+// downloaded again. Empty files are placeholders left by `new-day`.
+pub fn fetch_input(client: &Client, root: &Path, day: u8) -> AocResult<Fetched> {
+    let path = root.join(runner::input_path(day).trim_start_matches("./"));
+    if runner::has_input(&path) {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let response = client.send(Request::get(&format!("{}/input", client.day_url(day))))?;
+    if response.status != 200 {
+        return Err(AocError::Http {
+            status: response.status,
+            message: response.body.lines().next().unwrap_or("").trim().to_string(),
+        });
+    }
+    if response.body.is_empty() {
+        return Err(AocError::Http {
+            status: response.status,
+            message: format!("empty input for day {}", day),
+        });
+    }
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    // Write next to the target first so an interrupted download never looks cached
+    let partial = path.with_extension("part");
+    fs::write(&partial, &response.body)?;
+    fs::rename(&partial, &path)?;
+    Ok(Fetched::Downloaded(path))
+}
+
+// The puzzle description, part two is only included once part one is solved
+pub fn fetch_page(client: &Client, day: u8) -> AocResult<String> {
+    let response = client.send(Request::get(&client.day_url(day)))?;
+    if response.status != 200 {
+        return Err(AocError::Http {
+            status: response.status,
+            message: format!("cannot download the puzzle page for day {}", day),
+        });
+    }
+    Ok(response.body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::tests::{ ok_response, stand_in_server };
+
+    fn temp_root(name: &str) -> PathBuf {
+        let root = env::temp_dir().join(format!("aoc2025_fetch_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        root
+    }
+
+    #[test]
+    fn downloads_once_then_serves_cache() {
+        let root = temp_root("cache");
+        let (base_url, server) = stand_in_server(vec![ok_response("1,2,3\n")]);
+        let client = Client::new(&base_url, "abc123\n");
+
+        let fetched = fetch_input(&client, &root, 8).unwrap();
+        let path = root.join("input/day_08.txt");
+        assert_eq!(fetched, Fetched::Downloaded(path.clone()));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1,2,3\n");
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2025/day/8/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("Cookie: session=abc123\r\n"));
+        assert!(requests[0].contains(&format!("User-Agent: {}\r\n", USER_AGENT)));
+
+        // The stand-in server is gone, so this only succeeds from the cache
+        assert_eq!(fetch_input(&client, &root, 8).unwrap(), Fetched::Cached(path));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn replaces_empty_placeholder() {
+        let root = temp_root("placeholder");
+        fs::create_dir_all(root.join("input")).unwrap();
+        fs::write(root.join("input/day_03.txt"), "").unwrap();
+        let (base_url, server) = stand_in_server(vec![ok_response("987\n")]);
+
+        let fetched = fetch_input(&Client::new(&base_url, "abc"), &root, 3).unwrap();
+        assert!(matches!(fetched, Fetched::Downloaded(_)));
+        assert_eq!(fs::read_to_string(root.join("input/day_03.txt")).unwrap(), "987\n");
+
+        server.join().unwrap();
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn error_status_writes_nothing() {
+        let root = temp_root("error");
+        let body = "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n";
+        let response = format!(
+            "HTTP/1.1 400 Bad Request\r\nContent-Length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        let (base_url, server) = stand_in_server(vec![response]);
+
+        let err = fetch_input(&Client::new(&base_url, "expired"), &root, 1).err().unwrap();
+        assert!(matches!(err, AocError::Http { status: 400, .. }));
+        assert!(!root.join("input/day_01.txt").exists());
+
+        server.join().unwrap();
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn session_from_file() {
+        let root = temp_root("session");
+        fs::write(root.join(SESSION_FILE), "from-file\n").unwrap();
+
+        // Only meaningful when the developer running the tests has no token exported
+        if env::var(SESSION_ENV).is_err() {
+            let client = Client::from_env(&root).unwrap();
+            assert_eq!(client.session, "from-file");
+        }
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,190 @@
+use std::panic::{ self, AssertUnwindSafe };
+
+use crate::generate::Rng;
+use crate::Solver;
+
+// Fuzz entry point for a day's parser. The bytes go through the same loader as puzzle files,
+// and lossily decoded straight into `parse` so invalid UTF-8 still reaches the parser.
+// Returning an error is fine, only a panic counts as a crash.
+pub fn parse_bytes<S: Solver>(data: &[u8]) {
+    let _ = S::parse_reader(data);
+    let _ = S::parse(&String::from_utf8_lossy(data));
+}
+
+// Bytes the puzzle formats give meaning to, plus a few that tend to break slicing
+const INTERESTING: &[&[u8]] = &[
+    b"0",
+    b"1",
+    b"9",
+    b"-",
+    b",",
+    b"\n",
+    b"\n\n",
+    b"\r\n",
+    b" ",
+    b"L",
+    b"R",
+    b"@",
+    b".",
+    b"^",
+    b"S",
+    b"+",
+    b"*",
+    b"/",
+    b"99999999999999999999",
+    b"-1",
+    "\u{e9}".as_bytes(),
+    "\u{feff}".as_bytes(),
+    b"\xff",
+];
+
+#[derive(Debug, PartialEq)]
+pub struct Crash {
+    pub iteration: u64,
+    pub input: Vec<u8>,
+    pub message: String,
+}
+
+fn random_input(rng: &mut Rng) -> Vec<u8> {
+    let mut data = Vec::new();
+    for _ in 0..rng.range(0, 40) {
+        if rng.percent(30) {
+            data.push(rng.range(0, 255) as u8);
+        } else {
+            data.extend_from_slice(rng.pick::<&[u8]>(INTERESTING));
+        }
+    }
+    data
+}
+
+// A few random edits on top of a valid input, these get much further into a parser
+fn mutate(rng: &mut Rng, seed: &[u8]) -> Vec<u8> {
+    let mut data = seed.to_vec();
+    for _ in 0..rng.range(1, 8) {
+        let at = rng.range(0, data.len() as u64) as usize;
+        match rng.range(0, 4) {
+            0 => {
+                data.splice(at..at, rng.pick(INTERESTING).iter().copied());
+            }
+            1 => {
+                let end = (at + rng.range(1, 8) as usize).min(data.len());
+                data.drain(at..end);
+            }
+            2 => {
+                if at < data.len() {
+                    data[at] = rng.range(0, 255) as u8;
+                }
+            }
+            3 => {
+                data.truncate(at);
+            }
+            _ => {
+                let end = (at + rng.range(1, 16) as usize).min(data.len());
+                let chunk = data[at..end].to_vec();
+                data.splice(at..at, chunk);
+            }
+        }
+    }
+    data
+}
+
+fn crashes(target: fn(&[u8]), data: &[u8]) -> Option<String> {
+    let payload = panic::catch_unwind(AssertUnwindSafe(|| target(data))).err()?;
+    let message = payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "panic with a non-string payload".to_string());
+    Some(message)
+}
+
+// Drops chunks of the input for as long as it still crashes
+fn minimize(target: fn(&[u8]), mut crash: Crash) -> Crash {
+    let mut chunk = crash.input.len().max(1);
+    while chunk > 0 {
+        let mut start = 0;
+        while start < crash.input.len() {
+            let end = (start + chunk).min(crash.input.len());
+            let mut smaller = crash.input.clone();
+            smaller.drain(start..end);
+            match crashes(target, &smaller) {
+                Some(message) => {
+                    crash.input = smaller;
+                    crash.message = message;
+                }
+                None => {
+                    start += chunk;
+                }
+            }
+        }
+        chunk /= 2;
+    }
+    crash
+}
+
+// Feeds `target` random and mutated inputs, half of them built from `corpus`. Stops at the
+// first panic and returns it with the input cut down as far as it still panics.
+pub fn run(target: fn(&[u8]), corpus: &[&str], iterations: u64, seed: u64) -> Option<Crash> {
+    let mut rng = Rng::new(seed);
+    for iteration in 0..iterations {
+        let data = if corpus.is_empty() || rng.percent(50) {
+            random_input(&mut rng)
+        } else {
+            let example = *rng.pick(corpus);
+            mutate(&mut rng, example.as_bytes())
+        };
+
+        if let Some(message) = crashes(target, &data) {
+            return Some(minimize(target, Crash { iteration, input: data, message }));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    fn picky(data: &[u8]) {
+        assert!(!data.windows(2).any(|w| w == b"-1"), "negative number");
+    }
+
+    #[test]
+    fn finds_and_minimizes_crash() {
+        let crash = run(picky, &["3-5\n10-14\n"], 10_000, 1).unwrap();
+        assert_eq!(crash.input, b"-1");
+        assert_eq!(crash.message, "negative number");
+    }
+
+    #[test]
+    fn known_crashers_return_errors() {
+        let cases: [(u8, &[u8]); 6] = [
+            (1, b"\n"),
+            (1, "\u{e9}5\n".as_bytes()),
+            (1, b"R\xff\n"),
+            (2, b"11\n"),
+            (6, b""),
+            (6, b"\n\n"),
+        ];
+        for (number, data) in cases {
+            let day = registry::find(number).unwrap();
+            (day.fuzz)(data);
+            assert!((day.run)(&String::from_utf8_lossy(data), day.parts).is_err());
+        }
+    }
+
+    #[test]
+    fn parsers_never_panic() {
+        for day in registry::all() {
+            if let Some(crash) = run(day.fuzz, &[day.example], 3000, 0) {
+                panic!(
+                    "day {} parser panicked on {:?}: {}",
+                    day.day,
+                    String::from_utf8_lossy(&crash.input),
+                    crash.message
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,410 @@
+use crate::{ AocError, AocResult };
+
+// SplitMix64, small and good enough to shuffle puzzle inputs. The same seed always gives the
+// same sequence, so a seed is all a bug report needs.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    // Uniform in `min..=max`
+    pub fn range(&mut self, min: u64, max: u64) -> u64 {
+        assert!(min <= max, "empty range {}..={}", min, max);
+        match (max - min).checked_add(1) {
+            Some(span) => min + self.next_u64() % span,
+            None => self.next_u64(),
+        }
+    }
+
+    // true with a chance of `percent` in 100
+    pub fn percent(&mut self, percent: u64) -> bool {
+        self.range(1, 100) <= percent
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0, items.len() as u64 - 1) as usize]
+    }
+}
+
+// Size knobs for the generators, each day only reads the ones that apply to it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Params {
+    pub moves: u64,
+    pub max_steps: u64,
+    pub ranges: u64,
+    pub range_width: u64,
+    pub banks: u64,
+    pub bank_length: u64,
+    pub width: u64,
+    pub height: u64,
+    pub density: u64,
+    pub ids: u64,
+    pub worksheets: u64,
+    pub rows: u64,
+    pub points: u64,
+    pub connections: u64,
+    pub max_coord: u64,
+    pub vertices: u64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            moves: 100,
+            max_steps: 500,
+            ranges: 10,
+            range_width: 1000,
+            banks: 20,
+            bank_length: 15,
+            width: 15,
+            height: 16,
+            density: 30,
+            ids: 50,
+            worksheets: 10,
+            rows: 3,
+            points: 20,
+            connections: 10,
+            max_coord: 1000,
+            vertices: 8,
+        }
+    }
+}
+
+impl Params {
+    pub const NAMES: [&'static str; 16] = [
+        "moves",
+        "max_steps",
+        "ranges",
+        "range_width",
+        "banks",
+        "bank_length",
+        "width",
+        "height",
+        "density",
+        "ids",
+        "worksheets",
+        "rows",
+        "points",
+        "connections",
+        "max_coord",
+        "vertices",
+    ];
+
+    // Applies a `name=value` override as given on the command line
+    pub fn set(&mut self, assignment: &str) -> AocResult<()> {
+        let (name, value) = assignment
+            .split_once('=')
+            .ok_or(AocError::invalid(format!("expected `name=value`, got `{}`", assignment)))?;
+        let value: u64 = value
+            .parse()
+            .map_err(|_| AocError::invalid(format!("invalid value for {}: `{}`", name, value)))?;
+
+        let field = match name {
+            "moves" => &mut self.moves,
+            "max_steps" => &mut self.max_steps,
+            "ranges" => &mut self.ranges,
+            "range_width" => &mut self.range_width,
+            "banks" => &mut self.banks,
+            "bank_length" => &mut self.bank_length,
+            "width" => &mut self.width,
+            "height" => &mut self.height,
+            "density" => &mut self.density,
+            "ids" => &mut self.ids,
+            "worksheets" => &mut self.worksheets,
+            "rows" => &mut self.rows,
+            "points" => &mut self.points,
+            "connections" => &mut self.connections,
+            "max_coord" => &mut self.max_coord,
+            "vertices" => &mut self.vertices,
+            _ => {
+                return Err(
+                    AocError::invalid(
+                        format!(
+                            "unknown size `{}`, expected one of {}",
+                            name,
+                            Params::NAMES.join(", ")
+                        )
+                    )
+                );
+            }
+        };
+        *field = value;
+        Ok(())
+    }
+}
+
+fn at_least(value: u64, min: u64, name: &str) -> AocResult<u64> {
+    if value < min {
+        return Err(AocError::invalid(format!("{} must be at least {}, got {}", name, min, value)));
+    }
+    Ok(value)
+}
+
+// A valid puzzle input for `day`, the same seed and params always give the same text
+pub fn generate(day: u8, seed: u64, params: &Params) -> AocResult<String> {
+    let mut rng = Rng::new(seed);
+    let rng = &mut rng;
+    match day {
+        1 => dial_moves(rng, params),
+        2 => id_ranges(rng, params),
+        3 => battery_banks(rng, params),
+        4 => paper_rolls(rng, params),
+        5 => fresh_ingredients(rng, params),
+        6 => worksheets(rng, params),
+        7 => splitter_map(rng, params),
+        8 => junction_boxes(rng, params),
+        9 => red_tiles(rng, params),
+        _ => Err(AocError::invalid(format!("no generator for day {}", day))),
+    }
+}
+
+fn dial_moves(rng: &mut Rng, params: &Params) -> AocResult<String> {
+    let max_steps = at_least(params.max_steps, 1, "max_steps")?;
+    let mut text = String::new();
+    for _ in 0..params.moves {
+        let direction = rng.pick(&['L', 'R']);
+        text.push_str(&format!("{}{}\n", direction, rng.range(1, max_steps)));
+    }
+    Ok(text)
+}
+
+fn id_ranges(rng: &mut Rng, params: &Params) -> AocResult<String> {
+    at_least(params.ranges, 1, "ranges")?;
+    let ranges: Vec<String> = (0..params.ranges)
+        .map(|_| {
+            // Spread the magnitudes so ids with 2 up to 10 digits all show up
+            let digits = rng.range(2, 10) as u32;
+            let min = rng.range(10u64.pow(digits - 1), 10u64.pow(digits) - 1);
+            let max = min + rng.range(0, params.range_width);
+            format!("{}-{}", min, max)
+        })
+        .collect();
+    Ok(format!("{}\n", ranges.join(",")))
+}
+
+fn battery_banks(rng: &mut Rng, params: &Params) -> AocResult<String> {
+    // Part two switches on 12 batteries per bank
+    let length = at_least(params.bank_length, 12, "bank_length")?;
+    let mut text = String::new();
+    for _ in 0..params.banks {
+        for _ in 0..length {
+            text.push(char::from(b'0' + rng.range(1, 9) as u8));
+        }
+        text.push('\n');
+    }
+    Ok(text)
+}
+
+fn paper_rolls(rng: &mut Rng, params: &Params) -> AocResult<String> {
+    let width = at_least(params.width, 1, "width")?;
+    let height = at_least(params.height, 1, "height")?;
+    let mut text = String::new();
+    for _ in 0..height {
+        for _ in 0..width {
+            text.push(if rng.percent(params.density) { '@' } else { '.' });
+        }
+        text.push('\n');
+    }
+    Ok(text)
+}
+
+fn fresh_ingredients(rng: &mut Rng, params: &Params) -> AocResult<String> {
+    let max_id = at_least(params.max_coord, 1, "max_coord")?;
+    let mut text = String::new();
+    for _ in 0..params.ranges {
+        let min = rng.range(1, max_id);
+        let max = (min + rng.range(0, params.range_width)).min(max_id);
+        text.push_str(&format!("{}-{}\n", min, max));
+    }
+    text.push('\n');
+    for _ in 0..params.ids {
+        text.push_str(&format!("{}\n", rng.range(1, max_id)));
+    }
+    Ok(text)
+}
+
+// Problems sit side by side separated by a blank column. Numbers have no zero digit and each
+// problem aligns its numbers left or right at random, like in the puzzle.
+fn worksheets(rng: &mut Rng, params: &Params) -> AocResult<String> {
+    at_least(params.worksheets, 1, "worksheets")?;
+    let rows = at_least(params.rows, 1, "rows")? as usize;
+    let mut lines = vec![String::new(); rows + 1];
+
+    for sheet in 0..params.worksheets {
+        let numbers: Vec<String> = (0..rows)
+            .map(|_| {
+                let digits = rng.range(1, 4);
+                (0..digits).map(|_| char::from(b'0' + rng.range(1, 9) as u8)).collect()
+            })
+            .collect();
+        let width = numbers
+            .iter()
+            .map(|n| n.len())
+            .max()
+            .unwrap_or(1);
+
+        if sheet > 0 {
+            for line in lines.iter_mut() {
+                line.push(' ');
+            }
+        }
+        let left = rng.percent(50);
+        for (line, number) in lines.iter_mut().zip(&numbers) {
+            if left {
+                line.push_str(&format!("{:<width$}", number, width = width));
+            } else {
+                line.push_str(&format!("{:>width$}", number, width = width));
+            }
+        }
+        let operation = rng.pick(&['+', '*']);
+        lines[rows].push_str(&format!("{:<width$}", operation, width = width));
+    }
+
+    Ok(lines.iter().map(|line| format!("{}\n", line)).collect())
+}
+
+// The start sits in the middle of the top row, splitters only on even rows like in the puzzle
+fn splitter_map(rng: &mut Rng, params: &Params) -> AocResult<String> {
+    let width = at_least(params.width, 1, "width")?;
+    let height = at_least(params.height, 1, "height")?;
+    let mut text = String::new();
+    for y in 0..height {
+        for x in 0..width {
+            let cell = if y == 0 && x == width / 2 {
+                'S'
+            } else if y >= 2 && y % 2 == 0 && rng.percent(params.density) {
+                '^'
+            } else {
+                '.'
+            };
+            text.push(cell);
+        }
+        text.push('\n');
+    }
+    Ok(text)
+}
+
+// Names its connection count up front instead of leaving it to the box count
+fn junction_boxes(rng: &mut Rng, params: &Params) -> AocResult<String> {
+    at_least(params.points, 1, "points")?;
+    let mut text = format!("connections: {}\n", params.connections);
+    for _ in 0..params.points {
+        let (x, y, z) = (
+            rng.range(0, params.max_coord),
+            rng.range(0, params.max_coord),
+            rng.range(0, params.max_coord),
+        );
+        text.push_str(&format!("{},{},{}\n", x, y, z));
+    }
+    Ok(text)
+}
+
+// Outline of a histogram: columns of random width and height standing on y = 0, walked
+// up the left edge, across the tops and back down. Any even vertex count from 4 is possible.
+fn red_tiles(rng: &mut Rng, params: &Params) -> AocResult<String> {
+    let vertices = at_least(params.vertices, 4, "vertices")?;
+    if vertices % 2 != 0 {
+        return Err(AocError::invalid(format!("vertices must be even, got {}", vertices)));
+    }
+    let columns = (vertices - 2) / 2;
+    let max_coord = at_least(params.max_coord, 2 * columns, "max_coord")?;
+    let step = max_coord / columns;
+
+    let mut xs = vec![rng.range(0, step / 2)];
+    for _ in 0..columns {
+        let last = *xs.last().unwrap();
+        xs.push(last + rng.range(1, step / 2).max(1));
+    }
+
+    let mut points = vec![(xs[0], 0)];
+    let mut previous = 0;
+    for column in 0..columns as usize {
+        // Neighbouring columns differ in height so no vertex ends up in the middle of an edge
+        let mut height = rng.range(1, max_coord);
+        while height == previous {
+            height = rng.range(1, max_coord);
+        }
+        points.push((xs[column], height));
+        points.push((xs[column + 1], height));
+        previous = height;
+    }
+    points.push((xs[columns as usize], 0));
+
+    Ok(
+        points
+            .iter()
+            .map(|(x, y)| format!("{},{}\n", x, y))
+            .collect()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn rng_is_deterministic() {
+        let first: Vec<u64> = {
+            let mut rng = Rng::new(42);
+            (0..5).map(|_| rng.next_u64()).collect()
+        };
+        let mut rng = Rng::new(42);
+        assert_eq!((0..5).map(|_| rng.next_u64()).collect::<Vec<_>>(), first);
+        assert_ne!(Rng::new(43).next_u64(), first[0]);
+
+        for _ in 0..1000 {
+            let value = rng.range(3, 7);
+            assert!((3..=7).contains(&value));
+        }
+        assert_eq!(rng.range(5, 5), 5);
+    }
+
+    #[test]
+    fn generated_inputs_solve() {
+        for seed in 0..5 {
+            for day in registry::all() {
+                let input = generate(day.day, seed, &Params::default()).unwrap();
+                assert_eq!(input, generate(day.day, seed, &Params::default()).unwrap());
+                if let Err(e) = (day.run)(&input, day.parts) {
+                    panic!("day {} seed {}: {}\n{}", day.day, seed, e, input);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn polygon_has_requested_vertices() {
+        let mut params = Params::default();
+        params.set("vertices=12").unwrap();
+        let input = generate(9, 7, &params).unwrap();
+        assert_eq!(input.lines().count(), 12);
+
+        params.set("vertices=5").unwrap();
+        assert!(generate(9, 7, &params).is_err());
+    }
+
+    #[test]
+    fn params_reject_unknown_names() {
+        let mut params = Params::default();
+        params.set("moves=3").unwrap();
+        assert_eq!(params.moves, 3);
+        assert!(params.set("moves").is_err());
+        assert!(params.set("moves=-1").is_err());
+        assert!(params.set("speed=3").err().unwrap().to_string().contains("unknown size `speed`"));
+        assert!(generate(10, 0, &params).is_err());
+    }
+}
@@ -0,0 +1,238 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::{ Add, Mul, Sub };
+
+// Integer types usable as coordinates
+pub trait Coord: Copy +
+    Ord +
+    Hash +
+    Debug +
+    Add<Output = Self> +
+    Sub<Output = Self> +
+    Mul<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs_diff_to(self, other: Self) -> Self {
+        if self > other { self - other } else { other - self }
+    }
+}
+
+macro_rules! coord {
+    ($($t:ty),*) => {
+        $(impl Coord for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+        })*
+    };
+}
+
+coord!(i32, i64, isize, usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Coord> Point2<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Point2 { x, y }
+    }
+
+    pub fn manhattan(&self, other: &Self) -> T {
+        self.x.abs_diff_to(other.x) + self.y.abs_diff_to(other.y)
+    }
+
+    pub fn chebyshev(&self, other: &Self) -> T {
+        self.x.abs_diff_to(other.x).max(self.y.abs_diff_to(other.y))
+    }
+
+    pub fn distance_sq(&self, other: &Self) -> T {
+        let dx = self.x.abs_diff_to(other.x);
+        let dy = self.y.abs_diff_to(other.y);
+        dx * dx + dy * dy
+    }
+}
+
+impl<T: Coord> Point3<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan(&self, other: &Self) -> T {
+        self.x.abs_diff_to(other.x) + self.y.abs_diff_to(other.y) + self.z.abs_diff_to(other.z)
+    }
+
+    pub fn chebyshev(&self, other: &Self) -> T {
+        self.x
+            .abs_diff_to(other.x)
+            .max(self.y.abs_diff_to(other.y))
+            .max(self.z.abs_diff_to(other.z))
+    }
+
+    pub fn distance_sq(&self, other: &Self) -> T {
+        let dx = self.x.abs_diff_to(other.x);
+        let dy = self.y.abs_diff_to(other.y);
+        let dz = self.z.abs_diff_to(other.z);
+        dx * dx + dy * dy + dz * dz
+    }
+}
+
+impl<T: Coord> Add for Point2<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Point2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Coord> Sub for Point2<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Point2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Coord> Mul<T> for Point2<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Point2::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: Coord> Add for Point3<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Point3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T: Coord> Sub for Point3<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Point3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<T: Coord> Mul<T> for Point3<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Point3::new(self.x * factor, self.y * factor, self.z * factor)
+    }
+}
+
+// Axis-aligned and inclusive on both ends, so a single point has area 1
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect<T> {
+    pub min: Point2<T>,
+    pub max: Point2<T>,
+}
+
+impl<T: Coord> Rect<T> {
+    pub fn from_corners(a: Point2<T>, b: Point2<T>) -> Self {
+        Rect {
+            min: Point2::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Point2::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    // Smallest rectangle holding every point, None for no points
+    pub fn bounding<'a, I>(points: I) -> Option<Self>
+        where I: IntoIterator<Item = &'a Point2<T>>, T: 'a
+    {
+        let mut points = points.into_iter();
+        let first = *points.next()?;
+        Some(
+            points.fold(Rect { min: first, max: first }, |rect, p| Rect {
+                min: Point2::new(rect.min.x.min(p.x), rect.min.y.min(p.y)),
+                max: Point2::new(rect.max.x.max(p.x), rect.max.y.max(p.y)),
+            })
+        )
+    }
+
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x + T::ONE
+    }
+
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y + T::ONE
+    }
+
+    pub fn area(&self) -> T {
+        self.width() * self.height()
+    }
+
+    pub fn contains(&self, p: &Point2<T>) -> bool {
+        self.min.x <= p.x && p.x <= self.max.x && self.min.y <= p.y && p.y <= self.max.y
+    }
+
+    // Strictly inside, the border itself does not count
+    pub fn interior_contains(&self, p: &Point2<T>) -> bool {
+        self.min.x < p.x && p.x < self.max.x && self.min.y < p.y && p.y < self.max.y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point_distances() {
+        let a = Point2::new(1i64, 5);
+        let b = Point2::new(4, 1);
+        assert_eq!(a.manhattan(&b), 7);
+        assert_eq!(a.chebyshev(&b), 4);
+        assert_eq!(a.distance_sq(&b), 25);
+
+        let a = Point3::new(162i64, 817, 812);
+        let b = Point3::new(425, 690, 689);
+        assert_eq!(a.manhattan(&b), 263 + 127 + 123);
+        assert_eq!(a.chebyshev(&b), 263);
+        assert_eq!(a.distance_sq(&b), 263 * 263 + 127 * 127 + 123 * 123);
+
+        // Unsigned coordinates never underflow
+        assert_eq!(Point2::new(0usize, 3).manhattan(&Point2::new(2, 0)), 5);
+    }
+
+    #[test]
+    fn vector_arithmetic() {
+        let a = Point2::new(1, 2);
+        let b = Point2::new(3, -4);
+        assert_eq!(a + b, Point2::new(4, -2));
+        assert_eq!(a - b, Point2::new(-2, 6));
+        assert_eq!(b * 2, Point2::new(6, -8));
+        assert_eq!(Point3::new(1, 2, 3) + Point3::new(1, 1, 1) * 3, Point3::new(4, 5, 6));
+    }
+
+    #[test]
+    fn rectangles() {
+        let rect = Rect::from_corners(Point2::new(11i64, 1), Point2::new(2, 5));
+        assert_eq!(rect.min, Point2::new(2, 1));
+        assert_eq!((rect.width(), rect.height(), rect.area()), (10, 5, 50));
+        assert!(rect.contains(&Point2::new(2, 5)));
+        assert!(!rect.interior_contains(&Point2::new(2, 5)));
+        assert!(rect.interior_contains(&Point2::new(3, 4)));
+        assert_eq!(Rect::from_corners(Point2::new(3, 3), Point2::new(3, 3)).area(), 1);
+    }
+
+    #[test]
+    fn bounding_box() {
+        let points = [Point2::new(7, 1), Point2::new(11, 7), Point2::new(2, 3)];
+        let rect = Rect::bounding(&points).unwrap();
+        assert_eq!(rect, Rect { min: Point2::new(2, 1), max: Point2::new(11, 7) });
+        assert!(Rect::<i32>::bounding(&[]).is_none());
+    }
+}
@@ -0,0 +1,219 @@
+use crate::{ AocError, AocResult };
+
+// Cells are stored row by row, positions are (x, y) with y growing downwards
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+const DELTAS_4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const DELTAS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self where T: Clone {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    // `cell` maps a character to a cell, None rejects it. Rows must all be the same width.
+    pub fn parse<F>(input: &str, mut cell: F) -> AocResult<Self> where F: FnMut(char) -> Option<T> {
+        let mut cells = Vec::new();
+        let mut width = 0;
+        let mut height = 0;
+
+        for (y, line) in input.lines().enumerate() {
+            let row_width = line.chars().count();
+            if y == 0 {
+                width = row_width;
+            } else if row_width != width {
+                let message = format!("row has width {}, expected {}", row_width, width);
+                return Err(AocError::invalid_at(y + 1, message));
+            }
+
+            for (x, c) in line.chars().enumerate() {
+                let value = cell(c).ok_or(
+                    AocError::parse(y + 1, x + 1, format!("unexpected cell `{}`", c))
+                )?;
+                cells.push(value);
+            }
+            height += 1;
+        }
+
+        if height == 0 || width == 0 {
+            return Err(AocError::invalid("expected a grid, got an empty input"));
+        }
+        Ok(Grid { width, height, cells })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index(&self, x: usize, y: usize) -> Option<usize> {
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.index(x, y).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.index(x, y).map(|idx| &mut self.cells[idx])
+    }
+
+    // Returns false and leaves the grid untouched when (x, y) is outside
+    pub fn set(&mut self, x: usize, y: usize, value: T) -> bool {
+        match self.get_mut(x, y) {
+            Some(cell) => {
+                *cell = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &T> {
+        let range = if y < self.height { y * self.width..(y + 1) * self.width } else { 0..0 };
+        self.cells[range].iter()
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let count = if x < self.width { self.height } else { 0 };
+        self.cells.iter().skip(x).step_by(self.width.max(1)).take(count)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    fn offsets<'a>(
+        &self,
+        x: usize,
+        y: usize,
+        deltas: &'a [(isize, isize)]
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        let (width, height) = (self.width, self.height);
+        deltas.iter().filter_map(move |&(dx, dy)| {
+            let nx = x.checked_add_signed(dx)?;
+            let ny = y.checked_add_signed(dy)?;
+            (nx < width && ny < height).then_some((nx, ny))
+        })
+    }
+
+    // Orthogonal neighbours inside the grid
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.offsets(x, y, &DELTAS_4)
+    }
+
+    // Orthogonal and diagonal neighbours inside the grid
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.offsets(x, y, &DELTAS_8)
+    }
+
+    pub fn find_all<P>(&self, mut predicate: P) -> Vec<(usize, usize)> where P: FnMut(&T) -> bool {
+        self.positions()
+            .filter(|&(x, y)| predicate(&self.cells[y * self.width + x]))
+            .collect()
+    }
+
+    pub fn render<F>(&self, mut cell: F) -> String where F: FnMut(&T) -> char {
+        let mut text = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            text.extend(row.iter().map(&mut cell));
+            text.push('\n');
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<u8> {
+        Grid::parse("123\n456\n", |c| c.to_digit(10).map(|d| d as u8)).unwrap()
+    }
+
+    #[test]
+    fn parse_and_get() {
+        let grid = sample();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(0, 0), Some(&1));
+        assert_eq!(grid.get(2, 1), Some(&6));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get(0, 2), None);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let digit = |c: char| c.to_digit(10);
+        let err = Grid::parse("12\n3x\n", digit).err().unwrap();
+        assert_eq!(err.to_string(), "parse error at line 2, column 2: unexpected cell `x`");
+
+        let err = Grid::parse("12\n345\n", digit).err().unwrap();
+        assert_eq!(err.to_string(), "invalid input at line 2: row has width 3, expected 2");
+
+        assert!(Grid::parse("", digit).is_err());
+    }
+
+    #[test]
+    fn set_is_bounds_checked() {
+        let mut grid = Grid::new(2, 2, '.');
+        assert!(grid.set(1, 1, '#'));
+        assert!(!grid.set(2, 0, '#'));
+        assert_eq!(grid.render(|&c| c), "..\n.#\n");
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = sample();
+        assert_eq!(grid.row(1).copied().collect::<Vec<_>>(), vec![4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), vec![3, 6]);
+        assert_eq!(grid.row(2).count(), 0);
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(grid.rows().count(), 2);
+    }
+
+    #[test]
+    fn neighbours_stay_inside() {
+        let grid = sample();
+        let corner: Vec<_> = grid.neighbours4(0, 0).collect();
+        assert_eq!(corner, vec![(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours8(0, 0).count(), 3);
+        assert_eq!(grid.neighbours8(1, 0).count(), 5);
+        assert_eq!(grid.neighbours4(1, 1).count(), 3);
+    }
+
+    #[test]
+    fn find_and_render() {
+        let grid = sample();
+        assert_eq!(grid.find_all(|&d| d % 2 == 0), vec![(1, 0), (0, 1), (2, 1)]);
+        assert_eq!(
+            grid.render(|&d| char::from_digit(d as u32, 10).unwrap()),
+            "123\n456\n"
+        );
+    }
+}
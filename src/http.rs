@@ -0,0 +1,313 @@
+use std::io::{ Read, Write };
+use std::net::TcpStream;
+use std::process::{ Command, Stdio };
+use std::time::Duration;
+
+use crate::{ AocError, AocResult };
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+pub struct Request {
+    pub method: &'static str,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Request {
+    pub fn get(url: &str) -> Self {
+        Request {
+            method: "GET",
+            url: url.to_string(),
+            headers: Vec::new(),
+            body: None,
+        }
+    }
+
+    pub fn post_form(url: &str, form: &[(&str, &str)]) -> Self {
+        let body: Vec<String> = form
+            .iter()
+            .map(|(key, value)| format!("{}={}", url_encode(key), url_encode(value)))
+            .collect();
+
+        Request {
+            method: "POST",
+            url: url.to_string(),
+            headers: vec![(
+                "Content-Type".to_string(),
+                "application/x-www-form-urlencoded".to_string(),
+            )],
+            body: Some(body.join("&")),
+        }
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+// std has no TLS, so https goes through the system curl while plain http
+// (local stand-in servers) is spoken directly over a socket
+pub fn send(request: &Request) -> AocResult<Response> {
+    if let Some(rest) = request.url.strip_prefix("http://") {
+        send_plain(request, rest)
+    } else if request.url.starts_with("https://") {
+        send_curl(request)
+    } else {
+        Err(AocError::Config(format!("unsupported URL `{}`", request.url)))
+    }
+}
+
+fn send_plain(request: &Request, without_scheme: &str) -> AocResult<Response> {
+    let (authority, path) = match without_scheme.find('/') {
+        Some(idx) => without_scheme.split_at(idx),
+        None => (without_scheme, "/"),
+    };
+    let address = if authority.contains(':') {
+        authority.to_string()
+    } else {
+        format!("{}:80", authority)
+    };
+
+    let mut stream = TcpStream::connect(&address)?;
+    stream.set_read_timeout(Some(TIMEOUT))?;
+
+    let mut head = format!(
+        "{} {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n",
+        request.method,
+        path,
+        authority
+    );
+    for (name, value) in &request.headers {
+        head.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    let body = request.body.as_deref().unwrap_or("");
+    if request.body.is_some() {
+        head.push_str(&format!("Content-Length: {}\r\n", body.len()));
+    }
+    head.push_str("\r\n");
+
+    stream.write_all(head.as_bytes())?;
+    stream.write_all(body.as_bytes())?;
+
+    let mut raw = Vec::new();
+    stream.read_to_end(&mut raw)?;
+    parse_response(&raw)
+}
+
+// Headers carry the session cookie, so they go to curl on stdin rather than on its command
+// line where any local user could read them
+fn curl_command(request: &Request) -> (Command, String) {
+    let mut command = Command::new("curl");
+    command.args(["--silent", "--show-error", "--location", "--request", request.method]);
+    command.args(["--write-out", "\n%{http_code}"]);
+    let mut headers = String::new();
+    for (name, value) in &request.headers {
+        headers.push_str(&format!("{}: {}\n", name, value));
+    }
+    if !headers.is_empty() {
+        command.args(["--header", "@-"]);
+    }
+    if let Some(body) = &request.body {
+        command.args(["--data-raw", body]);
+    }
+    command.arg(&request.url);
+    (command, headers)
+}
+
+fn send_curl(request: &Request) -> AocResult<Response> {
+    let (mut command, headers) = curl_command(request);
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    child.stdin.take().unwrap().write_all(headers.as_bytes())?;
+
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err(AocError::Http {
+            status: 0,
+            message: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        });
+    }
+
+    let text = String::from_utf8_lossy(&output.stdout);
+    let (body, status) = text.rsplit_once('\n').unwrap_or(("", &text));
+    Ok(Response {
+        status: status.trim().parse().unwrap_or(0),
+        body: body.to_string(),
+    })
+}
+
+fn parse_response(raw: &[u8]) -> AocResult<Response> {
+    let malformed = |message: &str| AocError::Http {
+        status: 0,
+        message: message.to_string(),
+    };
+
+    let split = raw
+        .windows(4)
+        .position(|w| w == b"\r\n\r\n")
+        .ok_or(malformed("response has no header terminator"))?;
+    let head = String::from_utf8_lossy(&raw[..split]);
+    let body = &raw[split + 4..];
+
+    let mut lines = head.lines();
+    let status = lines
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|code| code.parse::<u16>().ok())
+        .ok_or(malformed("response has no status line"))?;
+
+    let chunked = lines.any(|line| {
+        let line = line.to_ascii_lowercase();
+        line.starts_with("transfer-encoding:") && line.contains("chunked")
+    });
+    let body = if chunked {
+        decode_chunked(body).ok_or(malformed("invalid chunked body"))?
+    } else {
+        body.to_vec()
+    };
+
+    Ok(Response {
+        status,
+        body: String::from_utf8_lossy(&body).to_string(),
+    })
+}
+
+fn decode_chunked(mut raw: &[u8]) -> Option<Vec<u8>> {
+    let mut body = Vec::new();
+    loop {
+        let line_end = raw.windows(2).position(|w| w == b"\r\n")?;
+        let size_line = std::str::from_utf8(&raw[..line_end]).ok()?;
+        let size_hex = size_line.split(';').next()?.trim();
+        let size = usize::from_str_radix(size_hex, 16).ok()?;
+        raw = &raw[line_end + 2..];
+        if size == 0 {
+            return Some(body);
+        }
+        body.extend_from_slice(raw.get(..size)?);
+        raw = raw.get(size + 2..)?;
+    }
+}
+
+pub fn url_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| {
+            match b {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                    (b as char).to_string()
+                }
+                _ => format!("%{:02X}", b),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use std::thread::{ self, JoinHandle };
+
+    // Serves one canned response per entry and hands back the raw requests it received
+    pub(crate) fn stand_in_server(responses: Vec<String>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for response in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut raw = Vec::new();
+                let mut buf = [0u8; 4096];
+                loop {
+                    let n = stream.read(&mut buf).unwrap();
+                    raw.extend_from_slice(&buf[..n]);
+                    if let Some(end) = raw.windows(4).position(|w| w == b"\r\n\r\n") {
+                        let head = String::from_utf8_lossy(&raw[..end]).to_lowercase();
+                        let length = head
+                            .lines()
+                            .find_map(|l| l.strip_prefix("content-length:"))
+                            .map(|l| l.trim().parse::<usize>().unwrap())
+                            .unwrap_or(0);
+                        if raw.len() >= end + 4 + length {
+                            break;
+                        }
+                    }
+                    if n == 0 {
+                        break;
+                    }
+                }
+                requests.push(String::from_utf8_lossy(&raw).to_string());
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+            requests
+        });
+
+        (base_url, handle)
+    }
+
+    pub(crate) fn ok_response(body: &str) -> String {
+        format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{}", body.len(), body)
+    }
+
+    #[test]
+    fn get_over_plain_http() {
+        let (base_url, server) = stand_in_server(vec![ok_response("hello")]);
+
+        let request = Request::get(&format!("{}/greeting", base_url)).header("User-Agent", "test");
+        let response = send(&request).unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, "hello");
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /greeting HTTP/1.1\r\n"));
+        assert!(requests[0].contains("User-Agent: test\r\n"));
+    }
+
+    #[test]
+    fn post_form_body() {
+        let (base_url, server) = stand_in_server(vec![ok_response("")]);
+
+        let request = Request::post_form(&base_url, &[("level", "1"), ("answer", "a b&c")]);
+        send(&request).unwrap();
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST / HTTP/1.1\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=1&answer=a%20b%26c"));
+    }
+
+    #[test]
+    fn curl_never_sees_the_token_on_its_command_line() {
+        let request = Request::get("https://adventofcode.com/2025/day/1/input")
+            .header("Cookie", "session=secret-token");
+        let (command, stdin) = curl_command(&request);
+
+        let args: Vec<String> = command
+            .get_args()
+            .map(|arg| arg.to_string_lossy().to_string())
+            .collect();
+        assert!(args.iter().all(|arg| !arg.contains("secret-token")), "{:?}", args);
+        assert!(args.windows(2).any(|pair| pair == ["--header", "@-"]));
+        assert_eq!(stdin, "Cookie: session=secret-token\n");
+    }
+
+    #[test]
+    fn parse_chunked_response() {
+        let raw = "HTTP/1.1 404 Not Found\r\nTransfer-Encoding: chunked\r\n\r\n\
+                   4\r\nWiki\r\n5\r\npedia\r\n0\r\n\r\n";
+        let response = parse_response(raw.as_bytes()).unwrap();
+        assert_eq!(response.status, 404);
+        assert_eq!(response.body, "Wikipedia");
+    }
+}
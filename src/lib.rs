@@ -1,10 +1,63 @@
 #![allow(dead_code)]
-pub mod day_01;
-pub mod day_02;
-pub mod day_03;
-pub mod day_04;
-pub mod day_05;
-pub mod day_06;
-pub mod day_07;
-pub mod day_08;
-pub mod day_09;
\ No newline at end of file
+
+// Personal puzzle inputs are not committed, tests using them skip when the file is absent
+// or still the empty placeholder from `new-day`.
+// Writes to stderr directly so the message is not swallowed by the test output capture.
+#[cfg(test)]
+macro_rules! require_input {
+    ($path:expr) => {
+        if !$crate::runner::has_input($path) {
+            use std::io::Write;
+            let _ = writeln!(
+                std::io::stderr(),
+                "skipping {}: {} is missing or empty",
+                module_path!(),
+                $path
+            );
+            return;
+        }
+    };
+}
+
+#[macro_use]
+pub mod log;
+#[macro_use]
+pub mod registry;
+#[macro_use]
+pub mod property;
+pub mod answers;
+pub mod bench;
+pub mod error;
+pub mod extract;
+pub mod fetch;
+pub mod fuzz;
+pub mod generate;
+pub mod geometry;
+pub mod grid;
+pub mod http;
+pub mod memory;
+pub mod normalize;
+pub mod parallel;
+pub mod parse;
+pub mod reference;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod solver;
+pub mod submit;
+pub mod watch;
+
+pub use error::{ AocError, AocResult };
+pub use solver::Solver;
+
+days! {
+    1 => day_01,
+    2 => day_02,
+    3 => day_03,
+    4 => day_04,
+    5 => day_05,
+    6 => day_06,
+    7 => day_07,
+    8 => day_08,
+    9 => day_09,
+}
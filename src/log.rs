@@ -0,0 +1,113 @@
+use std::fmt;
+use std::io::Write;
+use std::sync::atomic::{ AtomicU8, Ordering };
+
+pub const LEVEL_ENV: &str = "AOC_LOG";
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum Level {
+    Error = 1,
+    Info = 2,
+    Debug = 3,
+    Trace = 4,
+}
+
+impl Level {
+    pub fn parse(name: &str) -> Option<Level> {
+        match name.to_ascii_lowercase().as_str() {
+            "error" => Some(Level::Error),
+            "info" => Some(Level::Info),
+            "debug" => Some(Level::Debug),
+            "trace" => Some(Level::Trace),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Level::Error => "error",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        }
+    }
+}
+
+// 0 means off, so library calls stay silent until a binary opts in
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(0);
+
+pub fn set_level(level: Option<Level>) {
+    MAX_LEVEL.store(level.map_or(0, |l| l as u8), Ordering::Relaxed);
+}
+
+pub fn enabled(level: Level) -> bool {
+    level as u8 <= MAX_LEVEL.load(Ordering::Relaxed)
+}
+
+// Reads $AOC_LOG, an unknown level name is reported instead of silently ignored
+pub fn level_from_env() -> Result<Option<Level>, String> {
+    match std::env::var(LEVEL_ENV) {
+        Ok(name) if !name.is_empty() => {
+            Level::parse(&name)
+                .map(Some)
+                .ok_or(format!("invalid {} level: {}", LEVEL_ENV, name))
+        }
+        _ => Ok(None),
+    }
+}
+
+pub fn write(level: Level, target: &str, args: fmt::Arguments) {
+    if enabled(level) {
+        let _ = writeln!(std::io::stderr(), "[{} {}] {}", level.name(), target, args);
+    }
+}
+
+macro_rules! log_at {
+    ($level:expr, $($arg:tt)*) => {
+        $crate::log::write($level, module_path!(), format_args!($($arg)*))
+    };
+}
+
+macro_rules! error {
+    ($($arg:tt)*) => { log_at!($crate::log::Level::Error, $($arg)*) };
+}
+
+macro_rules! info {
+    ($($arg:tt)*) => { log_at!($crate::log::Level::Info, $($arg)*) };
+}
+
+macro_rules! debug {
+    ($($arg:tt)*) => { log_at!($crate::log::Level::Debug, $($arg)*) };
+}
+
+macro_rules! trace {
+    ($($arg:tt)*) => { log_at!($crate::log::Level::Trace, $($arg)*) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_levels() {
+        assert_eq!(Level::parse("info"), Some(Level::Info));
+        assert_eq!(Level::parse("TRACE"), Some(Level::Trace));
+        assert_eq!(Level::parse("verbose"), None);
+        assert!(Level::Error < Level::Trace);
+    }
+
+    // The level is process wide, so everything touching it lives in one test
+    #[test]
+    fn silent_until_enabled() {
+        assert!(!enabled(Level::Error));
+
+        set_level(Some(Level::Debug));
+        assert!(enabled(Level::Error));
+        assert!(enabled(Level::Debug));
+        assert!(!enabled(Level::Trace));
+        debug!("visible at debug {}", 1);
+
+        set_level(None);
+        assert!(!enabled(Level::Error));
+    }
+}
@@ -0,0 +1,938 @@
+use std::env;
+use std::fs;
+use std::io::{ self, Read };
+use std::path::Path;
+use std::panic;
+use std::process;
+use std::time::{ Duration, SystemTime, UNIX_EPOCH };
+
+use aoc2025::answers::{ self, AnswerKey, AnswerStore };
+use aoc2025::bench::{ self, BenchResult };
+use aoc2025::extract;
+use aoc2025::fetch::{ self, Client, Fetched };
+use aoc2025::fuzz;
+use aoc2025::generate::{ self, Params };
+use aoc2025::log::{ self, Level };
+use aoc2025::memory::AllocStats;
+use aoc2025::parallel::{ self, Job };
+use aoc2025::registry::{ self, Day };
+use aoc2025::report::{ self, Status };
+use aoc2025::runner::{ self, DayRun };
+use aoc2025::scaffold;
+use aoc2025::submit::{ self, Verdict, WrongAnswers };
+use aoc2025::watch::{ self, Event };
+use aoc2025::AocError;
+
+const USAGE: &str = "Usage:
+    aoc2025 run --day <N> [--part <1|2>] [--input <path>]
+    aoc2025 run --all [--part <1|2>] [--jobs <N>]
+    aoc2025 list
+    aoc2025 verify [--day <N>] [--answers <path>]
+    aoc2025 record [--day <N>] [--force] [--answers <path>]
+    aoc2025 report [--day <N>] [--format <json|csv>] [--output <path>] [--answers <path>]
+    aoc2025 bench [--day <N>] [--iterations <N>] [--example] [--output <path>]
+    aoc2025 new-day --day <N>
+    aoc2025 watch --day <N>
+    aoc2025 fetch --day <N> [--base-url <url>]
+    aoc2025 extract --day <N> [--page <path>] [--force] [--answers <path>]
+    aoc2025 submit --day <N> --part <1|2> [--answers <path>] [--base-url <url>]
+    aoc2025 generate --day <N> [--seed <N>] [--size <name=value>]... [--output <path>]
+    aoc2025 fuzz [--day <N>] [--iterations <N>] [--seed <N>]
+
+Without --input the puzzle input is read from stdin.
+Solver logging is off unless --log <error|info|debug|trace> or $AOC_LOG sets a level.
+fetch and submit read the session token from $AOC_SESSION or .aoc-session,
+extract does too when no saved --page is given.
+generate writes input/day_NN_seed_S.txt unless --output is given, sizes are
+moves, max_steps, ranges, range_width, banks, bank_length, width, height, density,
+ids, worksheets, rows, points, connections, max_coord and vertices.
+fuzz stops at the first parser panic and saves its input as input/day_NN_crash.txt.
+Builds with --features count-alloc also report allocations in run and bench.";
+
+#[cfg(feature = "count-alloc")]
+#[global_allocator]
+static ALLOCATOR: aoc2025::memory::CountingAlloc = aoc2025::memory::CountingAlloc;
+
+struct RunArgs {
+    day: Option<u8>,
+    part: Option<u8>,
+    input: Option<String>,
+    all: bool,
+    jobs: Option<usize>,
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs, String> {
+    let mut run_args = RunArgs {
+        day: None,
+        part: None,
+        input: None,
+        all: false,
+        jobs: None,
+    };
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--all" => {
+                run_args.all = true;
+            }
+            "--jobs" => {
+                let value = iter.next().ok_or("missing value for --jobs")?;
+                let jobs = value.parse().map_err(|_| format!("invalid job count: {}", value))?;
+                if jobs == 0 {
+                    return Err("--jobs must be at least 1".to_string());
+                }
+                run_args.jobs = Some(jobs);
+            }
+            "--day" | "--part" | "--input" => {
+                let value = iter.next().ok_or(format!("missing value for {}", arg))?;
+                match arg.as_str() {
+                    "--day" => {
+                        run_args.day = Some(
+                            value.parse().map_err(|_| format!("invalid day: {}", value))?
+                        );
+                    }
+                    "--part" => {
+                        run_args.part = Some(
+                            value.parse().map_err(|_| format!("invalid part: {}", value))?
+                        );
+                    }
+                    _ => {
+                        run_args.input = Some(value.clone());
+                    }
+                }
+            }
+            _ => {
+                return Err(format!("unknown argument: {}", arg));
+            }
+        }
+    }
+
+    if let Some(day) = run_args.day {
+        if registry::find(day).is_none() {
+            return Err(format!("day {} is not registered", day));
+        }
+    }
+    if let Some(part) = run_args.part {
+        if part != 1 && part != 2 {
+            return Err(format!("part must be 1 or 2, got {}", part));
+        }
+    }
+    if run_args.all == run_args.day.is_some() {
+        return Err("expected either --day <N> or --all".to_string());
+    }
+    if run_args.all && run_args.input.is_some() {
+        return Err("--input cannot be combined with --all".to_string());
+    }
+    if !run_args.all && run_args.jobs.is_some() {
+        return Err("--jobs only applies to --all".to_string());
+    }
+
+    Ok(run_args)
+}
+
+// Errors from the answer and wrong-answer files, prefixed with the file they came from
+fn in_file(path: &str) -> impl Fn(AocError) -> String + '_ {
+    move |e| format!("{}: {}", path, e)
+}
+
+fn read_input(path: Option<&str>) -> Result<String, String> {
+    match path {
+        Some(path) => fs::read_to_string(path).map_err(|e| format!("cannot read {}: {}", path, e)),
+        None => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|e| format!("cannot read stdin: {}", e))?;
+            Ok(input)
+        }
+    }
+}
+
+// Appends the allocation counts to a timing when the counting allocator is installed
+fn with_memory(elapsed: Duration, memory: Option<AllocStats>) -> String {
+    match memory {
+        Some(stats) => format!("{:?}, {}", elapsed, stats),
+        None => format!("{:?}", elapsed),
+    }
+}
+
+fn print_day(day: &Day, result: &DayRun) {
+    println!(
+        "Day {:02}: {} (parse {})",
+        day.day,
+        day.title,
+        with_memory(result.parse_elapsed, result.parse_memory)
+    );
+    for change in &result.input_changes {
+        println!("  Input: {}", change);
+    }
+    for part in &result.parts {
+        println!(
+            "  Part {}: {} ({})",
+            part.part,
+            part.answer,
+            with_memory(part.elapsed, part.memory)
+        );
+    }
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let run_args = parse_run_args(args)?;
+    let parts_for = |day: &Day| -> Vec<u8> {
+        match run_args.part {
+            Some(part) => vec![part],
+            None => day.parts.to_vec(),
+        }
+    };
+
+    if run_args.all {
+        let mut jobs = Vec::new();
+        for day in registry::all() {
+            let parts: Vec<u8> = parts_for(day)
+                .into_iter()
+                .filter(|&p| day.has_part(p))
+                .collect();
+            if parts.is_empty() {
+                continue;
+            }
+
+            for name in runner::INPUT_NAMES {
+                match runner::load_input(day, name) {
+                    Some(input) => jobs.push(Job { day, name, input, parts: parts.clone() }),
+                    None => {
+                        let path = runner::input_file(day.day, name);
+                        let day = day.day;
+                        eprintln!("Day {:02}: skipped {}, {} is missing or empty", day, name, path);
+                    }
+                }
+            }
+        }
+        if jobs.is_empty() {
+            return Err("no implemented parts to run".to_string());
+        }
+
+        let workers = run_args.jobs.unwrap_or_else(parallel::default_jobs);
+        let outcomes = parallel::run_jobs(&jobs, workers);
+        let store = AnswerStore::load(answers::DEFAULT_PATH)
+            .map_err(in_file(answers::DEFAULT_PATH))?;
+        let rows = parallel::summarize(&jobs, &outcomes, &store);
+        print!("{}", parallel::format_table(&rows));
+
+        let failed = rows
+            .iter()
+            .filter(|r| matches!(r.status, Status::Fail | Status::Error | Status::Panic))
+            .count();
+        if failed > 0 {
+            return Err(format!("{} of {} parts failed", failed, rows.len()));
+        }
+        return Ok(());
+    }
+
+    let day = registry::find(run_args.day.unwrap()).unwrap();
+    let parts = parts_for(day);
+    if let Some(&part) = parts.iter().find(|&&p| !day.has_part(p)) {
+        return Err(format!("day {} part {} is not implemented", day.day, part));
+    }
+
+    let input = read_input(run_args.input.as_deref())?;
+    let result = (day.run)(&input, &parts).map_err(|e| format!("day {}: {}", day.day, e))?;
+    print_day(day, &result);
+    Ok(())
+}
+
+struct StoreArgs {
+    day: Option<u8>,
+    force: bool,
+    answers: String,
+}
+
+fn parse_store_args(args: &[String]) -> Result<StoreArgs, String> {
+    let mut store_args = StoreArgs {
+        day: None,
+        force: false,
+        answers: answers::DEFAULT_PATH.to_string(),
+    };
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--force" => {
+                store_args.force = true;
+            }
+            "--day" => {
+                let value = iter.next().ok_or("missing value for --day")?;
+                let day = value.parse().map_err(|_| format!("invalid day: {}", value))?;
+                if registry::find(day).is_none() {
+                    return Err(format!("day {} is not registered", day));
+                }
+                store_args.day = Some(day);
+            }
+            "--answers" => {
+                store_args.answers = iter.next().ok_or("missing value for --answers")?.clone();
+            }
+            _ => {
+                return Err(format!("unknown argument: {}", arg));
+            }
+        }
+    }
+
+    Ok(store_args)
+}
+
+fn selected_days(day: Option<u8>) -> Vec<&'static Day> {
+    registry::all()
+        .iter()
+        .filter(|d| day.is_none_or(|n| d.day == n))
+        .collect()
+}
+
+fn verify(args: &[String]) -> Result<(), String> {
+    let store_args = parse_store_args(args)?;
+    let store = AnswerStore::load(&store_args.answers).map_err(in_file(&store_args.answers))?;
+    let (computed, errors) = runner::collect_answers(&selected_days(store_args.day));
+    let mut comparison = store.compare(&computed);
+    if let Some(day) = store_args.day {
+        comparison.missing.retain(|key| key.day == day);
+    }
+
+    for error in &errors {
+        eprintln!("error: {}", error);
+    }
+    println!("{} answers match", comparison.matched.len());
+    if !comparison.mismatches.is_empty() {
+        println!("Mismatches:");
+        for mismatch in &comparison.mismatches {
+            println!(
+                "  {}: expected {}, got {}",
+                mismatch.key,
+                mismatch.expected,
+                mismatch.actual
+            );
+        }
+    }
+    if !comparison.missing.is_empty() {
+        println!("Missing (recorded but not computed):");
+        for key in &comparison.missing {
+            println!("  {}", key);
+        }
+    }
+    if !comparison.new.is_empty() {
+        println!("Newly solved (not recorded yet):");
+        for (key, answer) in &comparison.new {
+            println!("  {}: {}", key, answer);
+        }
+    }
+
+    if comparison.is_ok() && errors.is_empty() {
+        Ok(())
+    } else {
+        Err(
+            format!(
+                "{} answers do not match, {} inputs failed",
+                comparison.mismatches.len(),
+                errors.len()
+            )
+        )
+    }
+}
+
+fn record(args: &[String]) -> Result<(), String> {
+    let store_args = parse_store_args(args)?;
+    let mut store = AnswerStore::load(&store_args.answers)
+        .map_err(in_file(&store_args.answers))?;
+    let (computed, errors) = runner::collect_answers(&selected_days(store_args.day));
+    for error in &errors {
+        eprintln!("error: {}", error);
+    }
+    let comparison = store.compare(&computed);
+
+    for (key, answer) in &comparison.new {
+        println!("Recorded {}: {}", key, answer);
+        store.insert(key.clone(), answer);
+    }
+    for mismatch in &comparison.mismatches {
+        if store_args.force {
+            println!("Replaced {}: {} -> {}", mismatch.key, mismatch.expected, mismatch.actual);
+            store.insert(mismatch.key.clone(), &mismatch.actual);
+        } else {
+            println!(
+                "Kept {}: recorded {}, computed {} (use --force to replace)",
+                mismatch.key,
+                mismatch.expected,
+                mismatch.actual
+            );
+        }
+    }
+
+    store
+        .save(&store_args.answers)
+        .map_err(|e| format!("cannot write {}: {}", store_args.answers, e))
+}
+
+fn report_days(args: &[String]) -> Result<(), String> {
+    let mut day: Option<u8> = None;
+    let mut format = "json".to_string();
+    let mut output: Option<String> = None;
+    let mut answers_path = answers::DEFAULT_PATH.to_string();
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let value = iter.next().ok_or(format!("missing value for {}", arg))?;
+        match arg.as_str() {
+            "--day" => {
+                let number = value.parse().map_err(|_| format!("invalid day: {}", value))?;
+                if registry::find(number).is_none() {
+                    return Err(format!("day {} is not registered", number));
+                }
+                day = Some(number);
+            }
+            "--format" => {
+                if value != "json" && value != "csv" {
+                    return Err(format!("format must be json or csv, got {}", value));
+                }
+                format = value.clone();
+            }
+            "--output" => {
+                output = Some(value.clone());
+            }
+            "--answers" => {
+                answers_path = value.clone();
+            }
+            _ => {
+                return Err(format!("unknown argument: {}", arg));
+            }
+        }
+    }
+
+    let store = AnswerStore::load(&answers_path).map_err(in_file(&answers_path))?;
+    let rows = report::build(&selected_days(day), &store);
+    let text = match format.as_str() {
+        "csv" => report::to_csv(&rows),
+        _ => report::to_json(&rows),
+    };
+
+    match output {
+        Some(output) => {
+            fs::write(&output, text).map_err(|e| format!("cannot write {}: {}", output, e))?;
+            println!("Wrote {}", output);
+        }
+        None => print!("{}", text),
+    }
+    Ok(())
+}
+
+struct BenchArgs {
+    day: Option<u8>,
+    iterations: usize,
+    example: bool,
+    output: Option<String>,
+}
+
+fn parse_bench_args(args: &[String]) -> Result<BenchArgs, String> {
+    let mut bench_args = BenchArgs {
+        day: None,
+        iterations: bench::DEFAULT_ITERATIONS,
+        example: false,
+        output: None,
+    };
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--example" => {
+                bench_args.example = true;
+            }
+            "--day" => {
+                let value = iter.next().ok_or("missing value for --day")?;
+                let day = value.parse().map_err(|_| format!("invalid day: {}", value))?;
+                if registry::find(day).is_none() {
+                    return Err(format!("day {} is not registered", day));
+                }
+                bench_args.day = Some(day);
+            }
+            "--iterations" => {
+                let value = iter.next().ok_or("missing value for --iterations")?;
+                bench_args.iterations = value
+                    .parse()
+                    .map_err(|_| format!("invalid iteration count: {}", value))?;
+            }
+            "--output" => {
+                bench_args.output = Some(iter.next().ok_or("missing value for --output")?.clone());
+            }
+            _ => {
+                return Err(format!("unknown argument: {}", arg));
+            }
+        }
+    }
+
+    Ok(bench_args)
+}
+
+fn print_bench(result: &BenchResult) {
+    for phase in &result.phases {
+        let memory = phase.memory.map_or(String::new(), |stats| format!("  {}", stats));
+        println!(
+            "Day {:02}  {:<8} {:<6} min {:>12?}  median {:>12?}  max {:>12?}{}",
+            result.day,
+            result.input,
+            phase.name,
+            phase.stats.min,
+            phase.stats.median,
+            phase.stats.max,
+            memory
+        );
+    }
+}
+
+fn bench(args: &[String]) -> Result<(), String> {
+    let bench_args = parse_bench_args(args)?;
+    let mut results = Vec::new();
+
+    for day in selected_days(bench_args.day) {
+        if day.parts.is_empty() {
+            continue;
+        }
+
+        // Prefer the personal input, it is the one worth optimising for
+        let path = runner::input_path(day.day);
+        let (name, input) = match fs::read_to_string(&path) {
+            Ok(input) if !bench_args.example => ("input", input),
+            _ => ("example", day.example.to_string()),
+        };
+
+        match bench::bench_day(day, name, &input, bench_args.iterations) {
+            Ok(result) => {
+                print_bench(&result);
+                results.push(result);
+            }
+            Err(e) => eprintln!("Day {:02}: {}", day.day, e),
+        }
+    }
+
+    if let Some(output) = &bench_args.output {
+        fs::write(output, bench::to_tsv(&results)).map_err(|e| {
+            format!("cannot write {}: {}", output, e)
+        })?;
+        println!("Wrote {}", output);
+    }
+    Ok(())
+}
+
+fn new_day(args: &[String]) -> Result<(), String> {
+    let day: u8 = match args {
+        [flag, value] if flag == "--day" => {
+            value.parse().map_err(|_| format!("invalid day: {}", value))?
+        }
+        _ => {
+            return Err("expected --day <N>".to_string());
+        }
+    };
+    if day == 0 || day > 25 {
+        return Err(format!("day must be between 1 and 25, got {}", day));
+    }
+
+    let written = scaffold::create_day(Path::new("."), day).map_err(|e| e.to_string())?;
+    for path in written {
+        println!("Wrote {}", path.display());
+    }
+    Ok(())
+}
+
+fn watch_day(args: &[String]) -> Result<(), String> {
+    let day: u8 = match args {
+        [flag, value] if flag == "--day" => {
+            value.parse().map_err(|_| format!("invalid day: {}", value))?
+        }
+        _ => {
+            return Err("expected --day <N>".to_string());
+        }
+    };
+    if day == 0 || day > 25 {
+        return Err(format!("day must be between 1 and 25, got {}", day));
+    }
+
+    watch::watch_day(Path::new("."), day, watch::DEFAULT_INTERVAL, |event| {
+        match event {
+            Event::Running => println!("Day {:02}: running", day),
+            Event::Finished(lines) => {
+                for line in lines {
+                    println!("{}", line);
+                }
+                println!("Day {:02}: watching for changes", day);
+            }
+            Event::Failed(message) => {
+                eprintln!("{}", message.trim_end());
+                println!("Day {:02}: watching for changes", day);
+            }
+        }
+    })
+}
+
+fn fetch_day(args: &[String]) -> Result<(), String> {
+    let mut day: Option<u8> = None;
+    let mut base_url: Option<String> = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--day" => {
+                let value = iter.next().ok_or("missing value for --day")?;
+                day = Some(value.parse().map_err(|_| format!("invalid day: {}", value))?);
+            }
+            "--base-url" => {
+                base_url = Some(iter.next().ok_or("missing value for --base-url")?.clone());
+            }
+            _ => {
+                return Err(format!("unknown argument: {}", arg));
+            }
+        }
+    }
+
+    let day = day.ok_or("expected --day <N>")?;
+    if day == 0 || day > 25 {
+        return Err(format!("day must be between 1 and 25, got {}", day));
+    }
+
+    let root = Path::new(".");
+    let mut client = Client::from_env(root).map_err(|e| e.to_string())?;
+    if let Some(base_url) = base_url {
+        client = client.with_base_url(&base_url);
+    }
+
+    match fetch::fetch_input(&client, root, day).map_err(|e| e.to_string())? {
+        Fetched::Cached(path) => println!("Cached {}", path.display()),
+        Fetched::Downloaded(path) => println!("Wrote {}", path.display()),
+    }
+    Ok(())
+}
+
+fn extract_day(args: &[String]) -> Result<(), String> {
+    let mut day: Option<u8> = None;
+    let mut page_path: Option<String> = None;
+    let mut answers_path = answers::DEFAULT_PATH.to_string();
+    let mut force = false;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg == "--force" {
+            force = true;
+            continue;
+        }
+        let value = iter.next().ok_or(format!("missing value for {}", arg))?;
+        match arg.as_str() {
+            "--day" => {
+                day = Some(value.parse().map_err(|_| format!("invalid day: {}", value))?);
+            }
+            "--page" => {
+                page_path = Some(value.clone());
+            }
+            "--answers" => {
+                answers_path = value.clone();
+            }
+            _ => {
+                return Err(format!("unknown argument: {}", arg));
+            }
+        }
+    }
+
+    let day = day.ok_or("expected --day <N>")?;
+    if day == 0 || day > 25 {
+        return Err(format!("day must be between 1 and 25, got {}", day));
+    }
+
+    let root = Path::new(".");
+    let html = match page_path {
+        Some(path) => read_input(Some(&path))?,
+        None => {
+            let client = Client::from_env(root).map_err(|e| e.to_string())?;
+            fetch::fetch_page(&client, day).map_err(|e| e.to_string())?
+        }
+    };
+    let page = extract::extract(&html).map_err(|e| e.to_string())?;
+
+    for path in extract::write_example(root, day, &page, force).map_err(|e| e.to_string())? {
+        println!("Wrote {}", path.display());
+    }
+
+    let mut store = AnswerStore::load(&answers_path).map_err(in_file(&answers_path))?;
+    for (idx, answer) in page.answers.iter().enumerate() {
+        let key = AnswerKey::new(day, idx as u8 + 1, "example");
+        match store.get(&key) {
+            Some(recorded) if recorded == answer => {}
+            Some(recorded) if !force => {
+                println!("Kept {}: recorded {}, page says {}", key, recorded, answer);
+            }
+            _ => {
+                println!("Recorded {}: {}", key, answer);
+                store.insert(key, answer);
+            }
+        }
+    }
+    store
+        .save(&answers_path)
+        .map_err(|e| format!("cannot write {}: {}", answers_path, e))
+}
+
+fn submit_part(args: &[String]) -> Result<(), String> {
+    let mut day: Option<u8> = None;
+    let mut part: Option<u8> = None;
+    let mut answers_path = answers::DEFAULT_PATH.to_string();
+    let mut base_url: Option<String> = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let value = iter.next().ok_or(format!("missing value for {}", arg))?;
+        match arg.as_str() {
+            "--day" => {
+                day = Some(value.parse().map_err(|_| format!("invalid day: {}", value))?);
+            }
+            "--part" => {
+                part = Some(value.parse().map_err(|_| format!("invalid part: {}", value))?);
+            }
+            "--answers" => {
+                answers_path = value.clone();
+            }
+            "--base-url" => {
+                base_url = Some(value.clone());
+            }
+            _ => {
+                return Err(format!("unknown argument: {}", arg));
+            }
+        }
+    }
+
+    let day = day.ok_or("expected --day <N>")?;
+    let day = registry::find(day).ok_or(format!("day {} is not registered", day))?;
+    let part = part.ok_or("expected --part <1|2>")?;
+    if !day.has_part(part) {
+        return Err(format!("day {:02} has no part {}", day.day, part));
+    }
+
+    let path = runner::input_path(day.day);
+    let input = read_input(Some(&path))?;
+    let result = (day.run)(&input, &[part]).map_err(in_file(&path))?;
+    let answer = result.parts[0].answer.clone();
+
+    let mut wrong = WrongAnswers::load(submit::WRONG_ANSWERS_PATH)
+        .map_err(in_file(submit::WRONG_ANSWERS_PATH))?;
+    if let Some(previous) = wrong.rules_out(day.day, part, &answer) {
+        return Err(
+            format!(
+                "not submitting {}, already rejected {} ({})",
+                answer,
+                previous.answer,
+                Verdict::Wrong(previous.hint)
+            )
+        );
+    }
+
+    let root = Path::new(".");
+    let mut client = Client::from_env(root).map_err(|e| e.to_string())?;
+    if let Some(base_url) = base_url {
+        client = client.with_base_url(&base_url);
+    }
+
+    println!("Day {:02} part {}: submitting {}", day.day, part, answer);
+    let verdict = submit::submit_answer(&client, day.day, part, &answer)
+        .map_err(|e| e.to_string())?;
+    println!("Day {:02} part {}: {}", day.day, part, verdict);
+
+    match verdict {
+        Verdict::Correct => {
+            let mut store = AnswerStore::load(&answers_path).map_err(in_file(&answers_path))?;
+            store.insert(AnswerKey::new(day.day, part, "input"), &answer);
+            store
+                .save(&answers_path)
+                .map_err(|e| format!("cannot write {}: {}", answers_path, e))
+        }
+        Verdict::Wrong(hint) => {
+            wrong.insert(day.day, part, &answer, hint);
+            wrong
+                .save(submit::WRONG_ANSWERS_PATH)
+                .map_err(|e| format!("cannot write {}: {}", submit::WRONG_ANSWERS_PATH, e))?;
+            Err(format!("{} was rejected", answer))
+        }
+        Verdict::AlreadySolved => Ok(()),
+        Verdict::RateLimited(_) | Verdict::Unknown(_) => Err("answer was not accepted".to_string()),
+    }
+}
+
+fn generate_input(args: &[String]) -> Result<(), String> {
+    let mut day: Option<u8> = None;
+    let mut seed: Option<u64> = None;
+    let mut params = Params::default();
+    let mut output: Option<String> = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let value = iter.next().ok_or(format!("missing value for {}", arg))?;
+        match arg.as_str() {
+            "--day" => {
+                day = Some(value.parse().map_err(|_| format!("invalid day: {}", value))?);
+            }
+            "--seed" => {
+                seed = Some(value.parse().map_err(|_| format!("invalid seed: {}", value))?);
+            }
+            "--size" => {
+                params.set(value).map_err(|e| e.to_string())?;
+            }
+            "--output" => {
+                output = Some(value.clone());
+            }
+            _ => {
+                return Err(format!("unknown argument: {}", arg));
+            }
+        }
+    }
+
+    let day = day.ok_or("expected --day <N>")?;
+    // Without a seed pick one from the clock, it is printed so the input can be rebuilt
+    let seed = seed.unwrap_or_else(|| {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_nanos() as u64)
+    });
+    let input = generate::generate(day, seed, &params).map_err(|e| e.to_string())?;
+
+    let output = output.unwrap_or_else(|| runner::input_file(day, &format!("seed_{}", seed)));
+    if let Some(dir) = Path::new(&output).parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("cannot create {}: {}", dir.display(), e))?;
+    }
+    fs::write(&output, input).map_err(|e| format!("cannot write {}: {}", output, e))?;
+    println!("Wrote {} (day {}, seed {})", output, day, seed);
+    Ok(())
+}
+
+fn fuzz_parsers(args: &[String]) -> Result<(), String> {
+    let mut day: Option<u8> = None;
+    let mut iterations: u64 = 100_000;
+    let mut seed: u64 = 0;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let value = iter.next().ok_or(format!("missing value for {}", arg))?;
+        match arg.as_str() {
+            "--day" => {
+                let number = value.parse().map_err(|_| format!("invalid day: {}", value))?;
+                if registry::find(number).is_none() {
+                    return Err(format!("day {} is not registered", number));
+                }
+                day = Some(number);
+            }
+            "--iterations" => {
+                iterations = value
+                    .parse()
+                    .map_err(|_| format!("invalid iteration count: {}", value))?;
+            }
+            "--seed" => {
+                seed = value.parse().map_err(|_| format!("invalid seed: {}", value))?;
+            }
+            _ => {
+                return Err(format!("unknown argument: {}", arg));
+            }
+        }
+    }
+
+    // Every crash panics many times while it is minimized, keep the terminal readable
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let mut crash = None;
+    for day in selected_days(day) {
+        if let Some(found) = fuzz::run(day.fuzz, &[day.example], iterations, seed) {
+            crash = Some((day, found));
+            break;
+        }
+        println!("Day {:02}: {} inputs, no panics", day.day, iterations);
+    }
+    panic::set_hook(hook);
+
+    let Some((day, crash)) = crash else {
+        return Ok(());
+    };
+    let path = runner::input_file(day.day, "crash");
+    fs::write(&path, &crash.input).map_err(|e| format!("cannot write {}: {}", path, e))?;
+    println!(
+        "Day {:02}: parser panicked after {} inputs: {}",
+        day.day,
+        crash.iteration + 1,
+        crash.message
+    );
+    println!("Wrote {} ({:?})", path, String::from_utf8_lossy(&crash.input));
+    Err(format!("day {} parser panicked", day.day))
+}
+
+fn list() -> Result<(), String> {
+    for day in registry::all() {
+        let parts: Vec<String> = day.parts
+            .iter()
+            .map(|p| p.to_string())
+            .collect();
+        let input = if runner::has_input(runner::input_path(day.day)) {
+            "input present"
+        } else {
+            "input missing"
+        };
+        println!(
+            "Day {:02}  {:<20}  parts [{}]  {}",
+            day.day,
+            day.title,
+            parts.join(", "),
+            input
+        );
+    }
+    Ok(())
+}
+
+// Takes `--log <level>` out of the arguments, it may appear anywhere on the command line
+fn init_logging(args: &mut Vec<String>) -> Result<(), String> {
+    let mut level = log::level_from_env()?;
+    if let Some(idx) = args.iter().position(|a| a == "--log") {
+        let name = args.get(idx + 1).ok_or("missing value for --log")?;
+        level = Some(Level::parse(name).ok_or(format!("invalid log level: {}", name))?);
+        args.drain(idx..idx + 2);
+    }
+    log::set_level(level);
+    Ok(())
+}
+
+fn main() {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    if let Err(message) = init_logging(&mut args) {
+        eprintln!("error: {}", message);
+        process::exit(2);
+    }
+
+    let result = match args.first().map(|s| s.as_str()) {
+        Some("run") => run(&args[1..]),
+        Some("list") => list(),
+        Some("verify") => verify(&args[1..]),
+        Some("record") => record(&args[1..]),
+        Some("report") => report_days(&args[1..]),
+        Some("bench") => bench(&args[1..]),
+        Some("new-day") => new_day(&args[1..]),
+        Some("watch") => watch_day(&args[1..]),
+        Some("fetch") => fetch_day(&args[1..]),
+        Some("extract") => extract_day(&args[1..]),
+        Some("submit") => submit_part(&args[1..]),
+        Some("generate") => generate_input(&args[1..]),
+        Some("fuzz") => fuzz_parsers(&args[1..]),
+        Some("help") | Some("--help") | Some("-h") => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        Some(command) => {
+            eprintln!("error: unknown command: {}\n\n{}", command, USAGE);
+            process::exit(2);
+        }
+        None => {
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    };
+
+    if let Err(message) = result {
+        eprintln!("error: {}", message);
+        process::exit(1);
+    }
+}
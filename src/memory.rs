@@ -0,0 +1,194 @@
+use std::alloc::{ GlobalAlloc, Layout, System };
+use std::cell::Cell;
+use std::fmt;
+use std::sync::atomic::{ AtomicBool, Ordering };
+
+// Wraps the system allocator and counts what every thread allocates. Only installed by
+// builds with the `count-alloc` feature (and by the unit tests), see `measure`.
+pub struct CountingAlloc;
+
+#[cfg(test)]
+#[global_allocator]
+static TEST_ALLOCATOR: CountingAlloc = CountingAlloc;
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Clone, Copy)]
+struct Counters {
+    allocations: u64,
+    bytes: u64,
+    live: u64,
+    peak: u64,
+}
+
+// Per thread so `run --all` workers and parallel tests do not count each other. Const
+// initialised without a destructor, so touching it never allocates.
+thread_local! {
+    static COUNTERS: Cell<Counters> = const {
+        Cell::new(Counters { allocations: 0, bytes: 0, live: 0, peak: 0 })
+    };
+}
+
+fn record(allocated: u64, freed: u64, new_block: bool) {
+    if !INSTALLED.load(Ordering::Relaxed) {
+        INSTALLED.store(true, Ordering::Relaxed);
+    }
+    // Fails while the thread is shutting down, those allocations are not worth counting
+    let _ = COUNTERS.try_with(|cell| {
+        let mut counters = cell.get();
+        if new_block {
+            counters.allocations += 1;
+        }
+        counters.bytes += allocated;
+        // Memory freed here may have been allocated on another thread
+        counters.live = (counters.live + allocated).saturating_sub(freed);
+        counters.peak = counters.peak.max(counters.live);
+        cell.set(counters);
+    });
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(layout.size() as u64, 0, true);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(layout.size() as u64, 0, true);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(0, layout.size() as u64, false);
+    }
+
+    // Counted as a new allocation of the bytes it grew by
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            let (old, new) = (layout.size() as u64, new_size as u64);
+            record(new.saturating_sub(old), old.saturating_sub(new), true);
+        }
+        new_ptr
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    pub allocations: u64,
+    pub bytes: u64,
+    // Most bytes live at once, on top of what was live before the call
+    pub peak: u64,
+}
+
+impl fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} allocs, {} allocated, peak {}",
+            self.allocations,
+            format_bytes(self.bytes),
+            format_bytes(self.peak)
+        )
+    }
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 { format!("{} B", bytes) } else { format!("{:.1} {}", value, UNITS[unit]) }
+}
+
+// true once `CountingAlloc` has served an allocation, i.e. it is the global allocator
+pub fn is_counting() -> bool {
+    INSTALLED.load(Ordering::Relaxed)
+}
+
+// Runs `f` and reports what it allocated on the current thread, None when the counting
+// allocator is not installed. Calls nest, an outer measurement includes the inner ones.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    let before = COUNTERS.with(|cell| {
+        let counters = cell.get();
+        cell.set(Counters { peak: counters.live, ..counters });
+        counters
+    });
+
+    let value = f();
+
+    let after = COUNTERS.with(|cell| {
+        let counters = cell.get();
+        cell.set(Counters { peak: counters.peak.max(before.peak), ..counters });
+        counters
+    });
+    let stats = AllocStats {
+        allocations: after.allocations - before.allocations,
+        bytes: after.bytes - before.bytes,
+        peak: after.peak.saturating_sub(before.live),
+    };
+    (value, is_counting().then_some(stats))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_allocations_and_peak() {
+        let (sum, stats) = measure(|| {
+            let first: Vec<u64> = Vec::with_capacity(100);
+            drop(first);
+            let second: Vec<u64> = (0..50).collect();
+            second.iter().sum::<u64>()
+        });
+        assert_eq!(sum, 1225);
+
+        let stats = stats.unwrap();
+        assert_eq!(stats.allocations, 2);
+        assert_eq!(stats.bytes, 800 + 400);
+        // The first vector is gone before the second one exists
+        assert_eq!(stats.peak, 800);
+    }
+
+    #[test]
+    fn nested_measurements() {
+        let kept: Vec<u8> = vec![0; 4096];
+        let (inner, outer) = measure(|| {
+            let (_, inner) = measure(|| Box::new(7u32));
+            let buffer: Vec<u8> = vec![1; 64];
+            drop(buffer);
+            inner.unwrap()
+        });
+        assert_eq!(inner.allocations, 1);
+        assert_eq!(inner.peak, 4);
+
+        // Memory live before the call does not count towards its peak
+        let outer = outer.unwrap();
+        assert_eq!(outer.allocations, 2);
+        assert_eq!(outer.peak, 64);
+        assert_eq!(kept.len(), 4096);
+
+        let (_, idle) = measure(|| 1 + 1);
+        assert_eq!(idle, Some(AllocStats::default()));
+    }
+
+    #[test]
+    fn human_readable_sizes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+        let stats = AllocStats { allocations: 3, bytes: 2048, peak: 100 };
+        assert_eq!(stats.to_string(), "3 allocs, 2.0 KiB allocated, peak 100 B");
+    }
+}
@@ -0,0 +1,110 @@
+use std::borrow::Cow;
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub enum Change {
+    Bom,
+    LineEndings(usize),
+    TrailingWhitespace(usize),
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Change::Bom => write!(f, "stripped UTF-8 byte order mark"),
+            Change::LineEndings(lines) => write!(f, "converted {} CR/CRLF line endings", lines),
+            Change::TrailingWhitespace(lines) => {
+                write!(f, "trimmed trailing whitespace on {} lines", lines)
+            }
+        }
+    }
+}
+
+pub struct Normalized<'a> {
+    pub text: Cow<'a, str>,
+    pub changes: Vec<Change>,
+}
+
+// Strips a BOM and turns CRLF / lone CR into LF. Trailing whitespace is only trimmed when
+// `trim_trailing` is set, layout-sensitive puzzles (day 06) keep it.
+pub fn normalize(input: &str, trim_trailing: bool) -> Normalized<'_> {
+    let mut changes = Vec::new();
+
+    let text = match input.strip_prefix('\u{feff}') {
+        Some(rest) => {
+            changes.push(Change::Bom);
+            rest
+        }
+        None => input,
+    };
+
+    let carriage_returns = text.matches('\r').count();
+    let needs_trim = trim_trailing && text.split(['\n', '\r']).any(|l| l.trim_end() != l);
+    if carriage_returns == 0 && !needs_trim {
+        return Normalized { text: Cow::Borrowed(text), changes };
+    }
+
+    let mut line_endings = 0;
+    let mut trimmed = 0;
+    let mut normalized = String::with_capacity(text.len());
+    let mut rest = text;
+    while !rest.is_empty() {
+        let end = rest.find(['\n', '\r']).unwrap_or(rest.len());
+        let line = &rest[..end];
+        let kept = if trim_trailing { line.trim_end() } else { line };
+        if kept.len() != line.len() {
+            trimmed += 1;
+        }
+        normalized.push_str(kept);
+
+        rest = &rest[end..];
+        if let Some(after) = rest.strip_prefix("\r\n").or(rest.strip_prefix('\r')) {
+            line_endings += 1;
+            normalized.push('\n');
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix('\n') {
+            normalized.push('\n');
+            rest = after;
+        }
+    }
+
+    if line_endings > 0 {
+        changes.push(Change::LineEndings(line_endings));
+    }
+    if trimmed > 0 {
+        changes.push(Change::TrailingWhitespace(trimmed));
+    }
+    Normalized { text: Cow::Owned(normalized), changes }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn untouched_input_is_borrowed() {
+        let normalized = normalize("3-5\n\n1\n", true);
+        assert!(matches!(normalized.text, Cow::Borrowed("3-5\n\n1\n")));
+        assert!(normalized.changes.is_empty());
+    }
+
+    #[test]
+    fn bom_and_line_endings() {
+        let normalized = normalize("\u{feff}3-5\r\n\r\n1\r2\n", true);
+        assert_eq!(normalized.text, "3-5\n\n1\n2\n");
+        assert_eq!(normalized.changes, vec![Change::Bom, Change::LineEndings(3)]);
+        assert_eq!(normalized.changes[1].to_string(), "converted 3 CR/CRLF line endings");
+    }
+
+    #[test]
+    fn trailing_whitespace_is_optional() {
+        let input = "123 328  \r\n 45 64 \n";
+        let trimmed = normalize(input, true);
+        assert_eq!(trimmed.text, "123 328\n 45 64\n");
+        assert_eq!(trimmed.changes, vec![Change::LineEndings(1), Change::TrailingWhitespace(2)]);
+
+        let kept = normalize(input, false);
+        assert_eq!(kept.text, "123 328  \n 45 64 \n");
+        assert_eq!(kept.changes, vec![Change::LineEndings(1)]);
+    }
+}
@@ -0,0 +1,279 @@
+use std::cell::{ Cell, RefCell };
+use std::panic::{ self, AssertUnwindSafe };
+use std::sync::atomic::{ AtomicUsize, Ordering };
+use std::sync::{ Arc, Mutex };
+use std::thread;
+use std::time::Duration;
+
+use crate::answers::{ AnswerKey, AnswerStore };
+use crate::registry::Day;
+use crate::report::Status;
+use crate::runner::DayRun;
+use crate::AocError;
+
+pub struct Job {
+    pub day: &'static Day,
+    // One of `runner::INPUT_NAMES`, answers are checked against the store under this name
+    pub name: &'static str,
+    pub input: String,
+    pub parts: Vec<u8>,
+}
+
+pub enum Outcome {
+    Finished(DayRun),
+    Failed(AocError),
+    Panicked(String),
+}
+
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+thread_local! {
+    static IN_JOB: Cell<bool> = const { Cell::new(false) };
+    // Where the job on this thread panicked, recorded by the hook `run_jobs` installs
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+// Only one `run_jobs` swaps the panic hook at a time, so the original is always restored
+static HOOK: Mutex<()> = Mutex::new(());
+
+// Runs the jobs on at most `workers` threads, a panicking solver only fails its own job.
+// Outcomes come back in job order. While jobs run their panics are kept off stderr and
+// reported in the outcome instead, other threads still reach the previous hook.
+pub fn run_jobs(jobs: &[Job], workers: usize) -> Vec<Outcome> {
+    let _guard = HOOK.lock().unwrap_or_else(|e| e.into_inner());
+    let previous = Arc::new(panic::take_hook());
+    let forward = Arc::clone(&previous);
+    panic::set_hook(
+        Box::new(move |info| {
+            if IN_JOB.get() {
+                let location = info.location().map(|l| l.to_string());
+                PANIC_LOCATION.with_borrow_mut(|slot| *slot = location);
+            } else {
+                forward(info);
+            }
+        })
+    );
+
+    let outcomes = run_workers(jobs, workers);
+
+    drop(panic::take_hook());
+    if let Ok(previous) = Arc::try_unwrap(previous) {
+        panic::set_hook(previous);
+    }
+    outcomes
+}
+
+fn run_workers(jobs: &[Job], workers: usize) -> Vec<Outcome> {
+    let next = AtomicUsize::new(0);
+    let outcomes: Mutex<Vec<Option<Outcome>>> = Mutex::new(
+        (0..jobs.len()).map(|_| None).collect()
+    );
+
+    thread::scope(|scope| {
+        for _ in 0..workers.clamp(1, jobs.len().max(1)) {
+            scope.spawn(|| {
+                loop {
+                    let idx = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(job) = jobs.get(idx) else {
+                        break;
+                    };
+
+                    IN_JOB.set(true);
+                    let result = panic::catch_unwind(
+                        AssertUnwindSafe(|| (job.day.run)(&job.input, &job.parts))
+                    );
+                    IN_JOB.set(false);
+                    let outcome = match result {
+                        Ok(Ok(run)) => Outcome::Finished(run),
+                        Ok(Err(e)) => Outcome::Failed(e),
+                        Err(payload) => {
+                            let mut message = panic_message(payload);
+                            if let Some(location) = PANIC_LOCATION.take() {
+                                message = format!("{} at {}", message, location);
+                            }
+                            error!("day {:02} panicked: {}", job.day.day, message);
+                            Outcome::Panicked(message)
+                        }
+                    };
+                    outcomes.lock().unwrap()[idx] = Some(outcome);
+                }
+            });
+        }
+    });
+
+    outcomes
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|outcome| outcome.expect("every job is run"))
+        .collect()
+}
+
+pub struct SummaryRow {
+    pub day: u8,
+    pub part: u8,
+    pub input: &'static str,
+    pub answer: String,
+    pub elapsed: Option<Duration>,
+    pub status: Status,
+}
+
+// One row per requested part of each job, answers are checked against the store entries
+// for the job's input name
+pub fn summarize(jobs: &[Job], outcomes: &[Outcome], store: &AnswerStore) -> Vec<SummaryRow> {
+    let mut rows = Vec::new();
+
+    for (job, outcome) in jobs.iter().zip(outcomes) {
+        match outcome {
+            Outcome::Finished(run) => {
+                for part in &run.parts {
+                    let key = AnswerKey::new(job.day.day, part.part, job.name);
+                    let status = match store.get(&key) {
+                        Some(expected) if expected == part.answer => Status::Pass,
+                        Some(_) => Status::Fail,
+                        None => Status::Unrecorded,
+                    };
+                    rows.push(SummaryRow {
+                        day: job.day.day,
+                        part: part.part,
+                        input: job.name,
+                        answer: part.answer.clone(),
+                        elapsed: Some(run.parse_elapsed + part.elapsed),
+                        status,
+                    });
+                }
+            }
+            Outcome::Failed(e) => {
+                for &part in &job.parts {
+                    rows.push(SummaryRow {
+                        day: job.day.day,
+                        part,
+                        input: job.name,
+                        answer: e.to_string(),
+                        elapsed: None,
+                        status: Status::Error,
+                    });
+                }
+            }
+            Outcome::Panicked(message) => {
+                for &part in &job.parts {
+                    rows.push(SummaryRow {
+                        day: job.day.day,
+                        part,
+                        input: job.name,
+                        answer: format!("panicked: {}", message),
+                        elapsed: None,
+                        status: Status::Panic,
+                    });
+                }
+            }
+        }
+    }
+
+    rows
+}
+
+pub fn format_table(rows: &[SummaryRow]) -> String {
+    let answer_width = rows
+        .iter()
+        .map(|r| r.answer.chars().count())
+        .chain(std::iter::once("Answer".len()))
+        .max()
+        .unwrap_or(0);
+
+    let mut text = format!(
+        "Day  Part  Input    {:<width$}  {:>12}  Status\n",
+        "Answer",
+        "Time",
+        width = answer_width
+    );
+    for row in rows {
+        let elapsed = row.elapsed.map_or("-".to_string(), |e| format!("{:.2?}", e));
+        text.push_str(
+            &format!(
+                "{:02}   {}     {:<7}  {:<width$}  {:>12}  {}\n",
+                row.day,
+                row.part,
+                row.input,
+                row.answer,
+                elapsed,
+                row.status.as_str(),
+                width = answer_width
+            )
+        );
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+    use crate::AocResult;
+
+    fn explode(_input: &str, _parts: &[u8]) -> AocResult<DayRun> {
+        panic!("solver bug");
+    }
+
+    static BROKEN: Day = Day {
+        day: 99,
+        title: "Broken",
+        example: "",
+        parts: &[1, 2],
+        run: explode,
+        fuzz: |_| {},
+    };
+
+    #[test]
+    fn panics_stay_in_their_job() {
+        let day = registry::find(1).unwrap();
+        let jobs = vec![
+            Job { day: &BROKEN, name: "input", input: String::new(), parts: vec![1, 2] },
+            Job { day, name: "example", input: day.example.to_string(), parts: vec![1, 2] },
+            Job { day, name: "input", input: "X1\n".to_string(), parts: vec![1] }
+        ];
+
+        let outcomes = run_jobs(&jobs, 2);
+        let Outcome::Panicked(message) = &outcomes[0] else {
+            panic!("broken day did not panic");
+        };
+        assert!(message.starts_with("solver bug at src/parallel.rs:"), "{}", message);
+        assert!(matches!(&outcomes[1], Outcome::Finished(run) if run.parts[0].answer == "3"));
+        assert!(matches!(&outcomes[2], Outcome::Failed(_)));
+
+        let store = AnswerStore::parse("01 1 example 3\n01 2 example 5\n01 1 input 3\n").unwrap();
+        let rows = summarize(&jobs, &outcomes, &store);
+        let statuses: Vec<&str> = rows
+            .iter()
+            .map(|r| r.status.as_str())
+            .collect();
+        assert_eq!(statuses, vec!["panic", "panic", "pass", "fail", "error"]);
+
+        let table = format_table(&rows);
+        assert_eq!(table.lines().count(), 6);
+        assert!(table.lines().next().unwrap().starts_with("Day  Part  Input    Answer"));
+        assert!(table.lines().nth(3).unwrap().starts_with("01   1     example  3"));
+        assert!(table.contains("panicked: solver bug"));
+    }
+
+    #[test]
+    fn more_workers_than_jobs() {
+        let day = registry::find(2).unwrap();
+        let input = day.example.to_string();
+        let jobs = vec![Job { day, name: "example", input, parts: vec![1] }];
+        assert_eq!(run_jobs(&jobs, 8).len(), 1);
+        assert!(run_jobs(&[], 4).is_empty());
+    }
+}
@@ -0,0 +1,171 @@
+use std::str::FromStr;
+
+use crate::error::column_of;
+use crate::grid::Grid;
+use crate::{ AocError, AocResult };
+
+// Like the day parsers, helpers that see a single line report line 1 and callers remap
+// the error with `at_line`
+
+// Every signed integer in `line`, a `-` directly after a digit is a separator ("3-5")
+pub fn ints<T: FromStr>(line: &str) -> AocResult<Vec<T>> {
+    let bytes = line.as_bytes();
+    let mut values = Vec::new();
+    let mut idx = 0;
+
+    while idx < bytes.len() {
+        let negative = bytes[idx] == b'-' &&
+            bytes.get(idx + 1).is_some_and(|b| b.is_ascii_digit()) &&
+            (idx == 0 || !bytes[idx - 1].is_ascii_digit());
+        if !negative && !bytes[idx].is_ascii_digit() {
+            idx += 1;
+            continue;
+        }
+
+        let start = idx;
+        idx += 1;
+        while idx < bytes.len() && bytes[idx].is_ascii_digit() {
+            idx += 1;
+        }
+        let number = &line[start..idx];
+        let value = number.parse::<T>().map_err(|_| {
+            AocError::parse(1, start + 1, format!("number `{}` is out of range", number))
+        })?;
+        values.push(value);
+    }
+
+    Ok(values)
+}
+
+// Parses `field`, a slice of `line`, naming it `what` in the error
+pub fn value<T: FromStr>(line: &str, field: &str, what: &str) -> AocResult<T> {
+    field.parse::<T>().map_err(|_| {
+        AocError::parse(1, column_of(line, field), format!("invalid {} `{}`", what, field))
+    })
+}
+
+// Splits on `delimiter` and parses every field as T
+pub fn fields<T: FromStr>(line: &str, delimiter: char, what: &str) -> AocResult<Vec<T>> {
+    line.split(delimiter)
+        .map(|field| value(line, field, what))
+        .collect()
+}
+
+// Two values around the first `delimiter`, `shape` describes the expected form, e.g. "x,y"
+pub fn pair<A: FromStr, B: FromStr>(
+    line: &str,
+    delimiter: char,
+    shape: &str,
+    what: &str
+) -> AocResult<(A, B)> {
+    let (a, b) = line
+        .split_once(delimiter)
+        .ok_or(AocError::parse(1, 1, format!("expected `{}`, got `{}`", shape, line)))?;
+    Ok((value(line, a, what)?, value(line, b, what)?))
+}
+
+// Exactly `N` values separated by `delimiter`
+pub fn tuple<T: FromStr, const N: usize>(
+    line: &str,
+    delimiter: char,
+    what: &str
+) -> AocResult<[T; N]> {
+    let values: Vec<T> = fields(line, delimiter, what)?;
+    let count = values.len();
+    values
+        .try_into()
+        .map_err(|_| AocError::invalid_at(1, format!("expected {} {}s, got {}", N, what, count)))
+}
+
+pub struct Section<'a> {
+    // 1-based number of the first line
+    pub line: usize,
+    pub lines: Vec<&'a str>,
+}
+
+impl<'a> Section<'a> {
+    // Lines paired with their line number in the whole input
+    pub fn numbered(&self) -> impl Iterator<Item = (usize, &'a str)> + '_ {
+        self.lines
+            .iter()
+            .enumerate()
+            .map(|(idx, &line)| (self.line + idx, line))
+    }
+}
+
+// Groups of lines separated by one or more blank lines
+pub fn sections(input: &str) -> Vec<Section<'_>> {
+    let mut sections: Vec<Section> = Vec::new();
+    let mut in_section = false;
+
+    for (idx, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            in_section = false;
+            continue;
+        }
+        if !in_section {
+            sections.push(Section { line: idx + 1, lines: Vec::new() });
+            in_section = true;
+        }
+        sections.last_mut().unwrap().lines.push(line);
+    }
+
+    sections
+}
+
+pub fn char_grid(input: &str) -> AocResult<Grid<char>> {
+    Grid::parse(input, Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signed_ints() {
+        assert_eq!(ints::<i64>("p=-3,4 v=12,-7").unwrap(), vec![-3, 4, 12, -7]);
+        assert_eq!(ints::<i64>("3-5").unwrap(), vec![3, 5]);
+        assert_eq!(ints::<u32>("no numbers").unwrap(), Vec::<u32>::new());
+
+        let err = ints::<u8>("1 300").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "parse error at line 1, column 3: number `300` is out of range"
+        );
+    }
+
+    #[test]
+    fn typed_pairs_and_tuples() {
+        assert_eq!(pair::<i64, u8>("10-14", '-', "start-end", "bound").unwrap(), (10, 14));
+
+        let err = pair::<i64, i64>("10", '-', "start-end", "bound").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "parse error at line 1, column 1: expected `start-end`, got `10`"
+        );
+
+        let err = pair::<i64, i64>("10-x", '-', "start-end", "bound").err().unwrap();
+        assert_eq!(err.to_string(), "parse error at line 1, column 4: invalid bound `x`");
+
+        assert_eq!(tuple::<i32, 3>("1,-2,3", ',', "coordinate").unwrap(), [1, -2, 3]);
+        let err = tuple::<i32, 3>("1,2", ',', "coordinate").err().unwrap();
+        assert_eq!(err.to_string(), "invalid input at line 1: expected 3 coordinates, got 2");
+    }
+
+    #[test]
+    fn blank_line_sections() {
+        let sections = sections("a\nb\n\n\nc\n  \nd\n");
+        assert_eq!(sections.len(), 3);
+        assert_eq!(sections[0].lines, vec!["a", "b"]);
+        assert_eq!(sections[1].line, 5);
+        assert_eq!(sections[2].numbered().collect::<Vec<_>>(), vec![(7, "d")]);
+        assert!(super::sections("").is_empty());
+    }
+
+    #[test]
+    fn char_grids() {
+        let grid = char_grid("ab\ncd\n").unwrap();
+        assert_eq!(grid.get(1, 1), Some(&'d'));
+        assert!(char_grid("ab\nc\n").is_err());
+    }
+}
@@ -0,0 +1,266 @@
+use std::env;
+use std::fmt::{ self, Debug };
+
+use crate::generate::Rng;
+
+// Returns early from a property with a formatted message when the condition does not hold
+#[cfg(test)]
+macro_rules! ensure {
+    ($cond:expr, $($arg:tt)*) => {
+        if !$cond {
+            return Err(format!($($arg)*));
+        }
+    };
+}
+
+pub const CASES_VAR: &str = "AOC_PROPTEST_CASES";
+pub const SEED_VAR: &str = "AOC_PROPTEST_SEED";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    pub cases: u64,
+    pub seed: u64,
+    // Upper bound on accepted shrink steps, keeps a pathological shrinker from looping forever
+    pub max_shrinks: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            cases: 256,
+            seed: 0,
+            max_shrinks: 1000,
+        }
+    }
+}
+
+impl Config {
+    // Defaults overridden by $AOC_PROPTEST_CASES and $AOC_PROPTEST_SEED, used to replay a
+    // reported failure or to run a longer search
+    pub fn from_env() -> Self {
+        let mut config = Config::default();
+        let read = |name: &str| {
+            env::var(name)
+                .ok()
+                .map(|value| {
+                    value.parse().unwrap_or_else(|_| panic!("invalid {}: `{}`", name, value))
+                })
+        };
+        if let Some(cases) = read(CASES_VAR) {
+            config.cases = cases;
+        }
+        if let Some(seed) = read(SEED_VAR) {
+            config.seed = seed;
+        }
+        config
+    }
+}
+
+// Simpler versions of a value, the most aggressive ones first
+pub trait Shrink: Sized {
+    fn shrink(&self) -> Vec<Self>;
+}
+
+macro_rules! shrink_unsigned {
+    ($($t:ty),*) => {
+        $(impl Shrink for $t {
+            fn shrink(&self) -> Vec<Self> {
+                let mut candidates = vec![0, self / 2, self.saturating_sub(1)];
+                candidates.dedup();
+                candidates.retain(|c| c < self);
+                candidates
+            }
+        })*
+    };
+}
+
+macro_rules! shrink_signed {
+    ($($t:ty),*) => {
+        $(impl Shrink for $t {
+            // Towards zero, a negative value also tries its positive twin
+            fn shrink(&self) -> Vec<Self> {
+                let mut candidates = vec![0, self / 2, self - self.signum()];
+                if *self < 0 {
+                    candidates.push(self.saturating_neg());
+                }
+                candidates.dedup();
+                candidates.retain(|c| c != self);
+                candidates
+            }
+        })*
+    };
+}
+
+shrink_unsigned!(u8, u32, u64, usize);
+shrink_signed!(i32, i64);
+
+impl Shrink for bool {
+    fn shrink(&self) -> Vec<Self> {
+        if *self { vec![false] } else { vec![] }
+    }
+}
+
+impl<A: Shrink + Clone, B: Shrink + Clone> Shrink for (A, B) {
+    fn shrink(&self) -> Vec<Self> {
+        let mut candidates: Vec<Self> = self.0
+            .shrink()
+            .into_iter()
+            .map(|a| (a, self.1.clone()))
+            .collect();
+        candidates.extend(self.1.shrink().into_iter().map(|b| (self.0.clone(), b)));
+        candidates
+    }
+}
+
+impl<A: Shrink + Clone, B: Shrink + Clone, C: Shrink + Clone> Shrink for (A, B, C) {
+    fn shrink(&self) -> Vec<Self> {
+        ((self.0.clone(), self.1.clone()), self.2.clone())
+            .shrink()
+            .into_iter()
+            .map(|((a, b), c)| (a, b, c))
+            .collect()
+    }
+}
+
+// Drops halves, then single elements, then shrinks elements in place
+impl<T: Shrink + Clone> Shrink for Vec<T> {
+    fn shrink(&self) -> Vec<Self> {
+        let mut candidates = Vec::new();
+        if self.len() > 1 {
+            candidates.push(self[..self.len() / 2].to_vec());
+            candidates.push(self[self.len() / 2..].to_vec());
+        }
+        for idx in 0..self.len() {
+            let mut fewer = self.clone();
+            fewer.remove(idx);
+            candidates.push(fewer);
+        }
+        for (idx, item) in self.iter().enumerate() {
+            for smaller in item.shrink() {
+                let mut shrunk = self.clone();
+                shrunk[idx] = smaller;
+                candidates.push(shrunk);
+            }
+        }
+        candidates
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Failure<T> {
+    pub seed: u64,
+    pub case: u64,
+    pub original: T,
+    pub minimal: T,
+    pub shrinks: usize,
+    pub message: String,
+}
+
+impl<T: Debug> fmt::Display for Failure<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "property failed on case {} (replay with {}={}), {} shrinks: {}\n\
+             minimal input: {:?}\noriginal input: {:?}",
+            self.case,
+            SEED_VAR,
+            self.seed,
+            self.shrinks,
+            self.message,
+            self.minimal,
+            self.original
+        )
+    }
+}
+
+// Case `n` draws from its own generator seeded with `seed + n`, so setting the seed to the
+// reported value replays the failing case first
+pub fn run<T, G, P>(config: &Config, generate: G, property: P) -> Result<(), Failure<T>>
+    where T: Shrink + Clone + Debug, G: Fn(&mut Rng) -> T, P: Fn(&T) -> Result<(), String>
+{
+    for case in 0..config.cases {
+        let seed = config.seed.wrapping_add(case);
+        let original = generate(&mut Rng::new(seed));
+        let Err(message) = property(&original) else {
+            continue;
+        };
+
+        let mut minimal = original.clone();
+        let mut message = message;
+        let mut shrinks = 0;
+        'shrinking: while shrinks < config.max_shrinks {
+            for candidate in minimal.shrink() {
+                if let Err(smaller_message) = property(&candidate) {
+                    minimal = candidate;
+                    message = smaller_message;
+                    shrinks += 1;
+                    continue 'shrinking;
+                }
+            }
+            break;
+        }
+
+        return Err(Failure { seed, case, original, minimal, shrinks, message });
+    }
+    Ok(())
+}
+
+// Test entry point, panics with the shrunk counterexample
+pub fn check<T, G, P>(generate: G, property: P)
+    where T: Shrink + Clone + Debug, G: Fn(&mut Rng) -> T, P: Fn(&T) -> Result<(), String>
+{
+    if let Err(failure) = run(&Config::from_env(), generate, property) {
+        panic!("{}", failure);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn passing_property() {
+        let config = Config { cases: 50, ..Config::default() };
+        let result = run(
+            &config,
+            |rng| rng.range(0, 1000),
+            |&n| {
+                ensure!(n * 2 >= n, "{} doubled is smaller", n);
+                Ok(())
+            }
+        );
+        assert_eq!(result, Ok(()));
+    }
+
+    fn numbers(rng: &mut Rng) -> Vec<u64> {
+        (0..rng.range(0, 20)).map(|_| rng.range(0, 100)).collect()
+    }
+
+    fn small_sum(numbers: &[u64]) -> Result<(), String> {
+        let sum: u64 = numbers.iter().sum();
+        ensure!(sum < 50, "sum {} is too large", sum);
+        Ok(())
+    }
+
+    #[test]
+    fn shrinks_to_minimal_counterexample() {
+        let config = Config::default();
+        let failure = run(&config, numbers, |n| small_sum(n)).unwrap_err();
+        assert_eq!(failure.minimal, vec![50]);
+        assert_eq!(failure.message, "sum 50 is too large");
+
+        // The reported seed replays the same failure as the first case
+        let replay = Config { seed: failure.seed, cases: 1, ..config };
+        let again = run(&replay, numbers, |n| small_sum(n)).unwrap_err();
+        assert_eq!(again.original, failure.original);
+    }
+
+    #[test]
+    fn shrink_candidates() {
+        assert_eq!(10u64.shrink(), vec![0, 5, 9]);
+        assert_eq!(0u64.shrink(), Vec::<u64>::new());
+        assert_eq!((-6i64).shrink(), vec![0, -3, -5, 6]);
+        assert_eq!(vec![3u8].shrink(), vec![vec![], vec![0], vec![1], vec![2]]);
+        assert_eq!((1u32, true).shrink(), vec![(0, true), (1, false)]);
+    }
+}
@@ -0,0 +1,485 @@
+use std::collections::BTreeSet;
+use std::fmt;
+use std::panic::{ self, AssertUnwindSafe };
+
+use crate::day_08;
+use crate::generate::{ self, Params };
+use crate::registry::Day;
+use crate::AocResult;
+
+// Slow but obviously correct solutions, written straight from the puzzle text without any of
+// the shortcuts the real solvers take. They trust their input, it comes from `generate`.
+pub fn solve(day: u8, part: u8, input: &str) -> Option<String> {
+    let answer = match (day, part) {
+        (1, _) => dial(input, part).to_string(),
+        (2, _) => gift_shop(input, part).to_string(),
+        (3, 1) => lobby(input, 2).to_string(),
+        (3, 2) => lobby(input, 12).to_string(),
+        (4, _) => printing_department(input, part).to_string(),
+        (5, _) => cafeteria(input, part).to_string(),
+        (6, _) => trash_compactor(input, part).to_string(),
+        (7, _) => laboratories(input, part).to_string(),
+        (8, _) => playground(input, part).to_string(),
+        (9, _) => movie_theater(input, part).to_string(),
+        _ => {
+            return None;
+        }
+    };
+    Some(answer)
+}
+
+fn numbers(line: &str, delim: char) -> Vec<i64> {
+    line.split(delim)
+        .map(|n| n.parse().unwrap())
+        .collect()
+}
+
+// Turns the dial one click at a time, part 1 only looks where each rotation ends
+fn dial(input: &str, part: u8) -> i64 {
+    let mut position = 50;
+    let mut zeros = 0;
+    for line in input.lines() {
+        let step = if line.starts_with('L') { 99 } else { 1 };
+        let clicks: i64 = line[1..].parse().unwrap();
+        for click in 1..=clicks {
+            position = (position + step) % 100;
+            if position == 0 && (part == 2 || click == clicks) {
+                zeros += 1;
+            }
+        }
+    }
+    zeros
+}
+
+// An id is invalid when it is some block of digits repeated, twice for part 1 and at least
+// twice for part 2
+fn gift_shop(input: &str, part: u8) -> i64 {
+    let mut sum = 0;
+    for range in input.trim().split(',') {
+        let bounds = numbers(range, '-');
+        for id in bounds[0]..=bounds[1] {
+            let digits = id.to_string();
+            let invalid = (1..digits.len()).any(|len| {
+                let repeats = digits.len() / len;
+                digits.len() % len == 0 &&
+                    (part == 2 || repeats == 2) &&
+                    digits[..len].repeat(repeats) == digits
+            });
+            if invalid {
+                sum += id;
+            }
+        }
+    }
+    sum
+}
+
+// Tries every way to switch on `count` batteries and keeps the largest joltage
+fn lobby(input: &str, count: usize) -> i64 {
+    fn best(digits: &[u8], count: usize) -> Option<i64> {
+        if count == 0 {
+            return Some(0);
+        }
+        (0..digits.len())
+            .filter_map(|idx| {
+                let rest = best(&digits[idx + 1..], count - 1)?;
+                Some((digits[idx] as i64) * (10i64).pow((count - 1) as u32) + rest)
+            })
+            .max()
+    }
+
+    input
+        .lines()
+        .map(|bank| {
+            let digits: Vec<u8> = bank.bytes().map(|b| b - b'0').collect();
+            best(&digits, count).unwrap()
+        })
+        .sum()
+}
+
+// Removes accessible rolls one at a time, rescanning the whole floor after each
+fn printing_department(input: &str, part: u8) -> i64 {
+    let mut floor: Vec<Vec<bool>> = input
+        .lines()
+        .map(|line| line.chars().map(|c| c == '@').collect())
+        .collect();
+    let accessible = |floor: &Vec<Vec<bool>>, x: usize, y: usize| {
+        let mut neighbours = 0;
+        for ny in y.saturating_sub(1)..=y + 1 {
+            for nx in x.saturating_sub(1)..=x + 1 {
+                let occupied = floor.get(ny).and_then(|row| row.get(nx)) == Some(&true);
+                if (nx, ny) != (x, y) && occupied {
+                    neighbours += 1;
+                }
+            }
+        }
+        floor[y][x] && neighbours < 4
+    };
+
+    let mut removed = 0;
+    loop {
+        let next = (0..floor.len())
+            .flat_map(|y| (0..floor[y].len()).map(move |x| (x, y)))
+            .filter(|&(x, y)| accessible(&floor, x, y))
+            .collect::<Vec<_>>();
+        if part == 1 {
+            return next.len() as i64;
+        }
+        let Some(&(x, y)) = next.first() else {
+            return removed;
+        };
+        floor[y][x] = false;
+        removed += 1;
+    }
+}
+
+// Lists every fresh id one by one
+fn cafeteria(input: &str, part: u8) -> i64 {
+    let (ranges, ids) = input.split_once("\n\n").unwrap();
+    let ranges: Vec<Vec<i64>> = ranges
+        .lines()
+        .map(|line| numbers(line, '-'))
+        .collect();
+
+    if part == 1 {
+        return ids
+            .lines()
+            .map(|id| id.parse::<i64>().unwrap())
+            .filter(|id| ranges.iter().any(|r| r[0] <= *id && *id <= r[1]))
+            .count() as i64;
+    }
+    let fresh: BTreeSet<i64> = ranges
+        .iter()
+        .flat_map(|r| r[0]..=r[1])
+        .collect();
+    fresh.len() as i64
+}
+
+// Cuts the sheet at blank columns, part 1 reads numbers along rows and part 2 down columns
+fn trash_compactor(input: &str, part: u8) -> i64 {
+    let lines: Vec<Vec<char>> = input
+        .lines()
+        .map(|line| line.chars().collect())
+        .collect();
+    let width = lines
+        .iter()
+        .map(|line| line.len())
+        .max()
+        .unwrap();
+    let cell = |x: usize, y: usize| lines[y].get(x).copied().unwrap_or(' ');
+    // The last row holds the operators, every row above it numbers
+    let rows = lines.len() - 1;
+
+    let mut total = 0;
+    let mut start = 0;
+    for end in 0..=width {
+        if end < width && (0..lines.len()).any(|y| cell(end, y) != ' ') {
+            continue;
+        }
+        if start < end {
+            let text = |xs: &mut dyn Iterator<Item = usize>, y: usize| -> String {
+                xs.map(|x| cell(x, y)).collect()
+            };
+            let problem: Vec<i64> = if part == 1 {
+                (0..rows).map(|y| text(&mut (start..end), y).trim().parse().unwrap()).collect()
+            } else {
+                (start..end)
+                    .map(|x| {
+                        // Shorter numbers leave gaps, the digits still read top to bottom
+                        let digits: String = (0..rows)
+                            .map(|y| cell(x, y))
+                            .filter(|&c| c != ' ')
+                            .collect();
+                        digits.parse().unwrap()
+                    })
+                    .collect()
+            };
+            let operator = text(&mut (start..end), rows);
+            total += match operator.trim() {
+                "+" => problem.iter().sum::<i64>(),
+                "*" => problem.iter().product(),
+                other => panic!("reference only knows + and *, got `{}`", other),
+            };
+        }
+        start = end + 1;
+    }
+    total
+}
+
+// Part 1 tracks the set of beam columns row by row, part 2 follows every timeline on its own.
+// A beam split off the side of the map is lost.
+fn laboratories(input: &str, part: u8) -> i64 {
+    let rows: Vec<Vec<char>> = input
+        .lines()
+        .map(|line| line.chars().collect())
+        .collect();
+    let start = rows[0]
+        .iter()
+        .position(|&c| c == 'S')
+        .unwrap();
+
+    if part == 1 {
+        let mut beams = BTreeSet::from([start as i64]);
+        let mut splits = 0;
+        for row in &rows[1..] {
+            let mut next = BTreeSet::new();
+            for &x in &beams {
+                if row[x as usize] == '^' {
+                    splits += 1;
+                    next.extend([x - 1, x + 1]);
+                } else {
+                    next.insert(x);
+                }
+            }
+            beams = next
+                .into_iter()
+                .filter(|&x| 0 <= x && x < (row.len() as i64))
+                .collect();
+        }
+        return splits;
+    }
+
+    fn timelines(rows: &[Vec<char>], x: i64, y: usize) -> i64 {
+        if x < 0 || x >= (rows[0].len() as i64) {
+            return 0;
+        }
+        if y == rows.len() {
+            return 1;
+        }
+        if rows[y][x as usize] == '^' {
+            timelines(rows, x - 1, y + 1) + timelines(rows, x + 1, y + 1)
+        } else {
+            timelines(rows, x, y + 1)
+        }
+    }
+    timelines(&rows, start as i64, 1)
+}
+
+// Adds wires in order of length and recomputes the circuits from scratch every time
+fn playground(input: &str, part: u8) -> i64 {
+    let (wires, input) = match input.strip_prefix("connections: ") {
+        Some(rest) => {
+            let (count, boxes) = rest.split_once('\n').unwrap();
+            (count.parse().unwrap(), boxes)
+        }
+        None => (day_08::default_connections(input.lines().count()), input),
+    };
+    let boxes: Vec<Vec<i64>> = input
+        .lines()
+        .map(|line| numbers(line, ','))
+        .collect();
+    let mut pairs = Vec::new();
+    for i in 0..boxes.len() {
+        for j in i + 1..boxes.len() {
+            let dist: i64 = (0..3).map(|k| (boxes[i][k] - boxes[j][k]).pow(2)).sum();
+            pairs.push((dist, i, j));
+        }
+    }
+    pairs.sort_by_key(|pair| pair.0);
+
+    let circuits = |wires: &[(i64, usize, usize)]| -> Vec<i64> {
+        let mut seen = vec![false; boxes.len()];
+        let mut sizes = Vec::new();
+        for first in 0..boxes.len() {
+            if seen[first] {
+                continue;
+            }
+            seen[first] = true;
+            let mut stack = vec![first];
+            let mut size = 0;
+            while let Some(node) = stack.pop() {
+                size += 1;
+                for &(_, i, j) in wires {
+                    for (from, to) in [(i, j), (j, i)] {
+                        if from == node && !seen[to] {
+                            seen[to] = true;
+                            stack.push(to);
+                        }
+                    }
+                }
+            }
+            sizes.push(size);
+        }
+        sizes.sort_by(|a, b| b.cmp(a));
+        sizes
+    };
+
+    if part == 1 {
+        let sizes = circuits(&pairs[..wires.min(pairs.len())]);
+        return sizes.iter().take(3).product();
+    }
+    for count in 1..=pairs.len() {
+        if circuits(&pairs[..count]).len() == 1 {
+            let (_, i, j) = pairs[count - 1];
+            return boxes[i][0] * boxes[j][0];
+        }
+    }
+    0
+}
+
+// Part 2 checks every tile of every candidate rectangle against the polygon
+fn movie_theater(input: &str, part: u8) -> i64 {
+    let corners: Vec<Vec<i64>> = input
+        .lines()
+        .map(|line| numbers(line, ','))
+        .collect();
+    let edges: Vec<(&Vec<i64>, &Vec<i64>)> = (0..corners.len())
+        .map(|i| (&corners[i], &corners[(i + 1) % corners.len()]))
+        .collect();
+
+    let on_edge = |x: i64, y: i64| {
+        edges.iter().any(|(a, b)| {
+            a[0].min(b[0]) <= x && x <= a[0].max(b[0]) && a[1].min(b[1]) <= y && y <= a[1].max(b[1])
+        })
+    };
+    // Ray to the right, a vertical edge counts when it spans y on the half-open [min, max)
+    let inside = |x: i64, y: i64| {
+        let crossings = edges
+            .iter()
+            .filter(|(a, b)| a[0] == b[0] && a[0] > x && a[1].min(b[1]) <= y && y < a[1].max(b[1]))
+            .count();
+        crossings % 2 == 1
+    };
+
+    let mut biggest = 0;
+    for (i, a) in corners.iter().enumerate() {
+        for b in &corners[i + 1..] {
+            let (min_x, max_x) = (a[0].min(b[0]), a[0].max(b[0]));
+            let (min_y, max_y) = (a[1].min(b[1]), a[1].max(b[1]));
+            let area = (max_x - min_x + 1) * (max_y - min_y + 1);
+            if area <= biggest {
+                continue;
+            }
+            let valid =
+                part == 1 ||
+                (min_x..=max_x).all(|x| (min_y..=max_y).all(|y| on_edge(x, y) || inside(x, y)));
+            if valid {
+                biggest = area;
+            }
+        }
+    }
+    biggest
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Mismatch {
+    pub day: u8,
+    pub part: u8,
+    pub seed: u64,
+    pub input: String,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "day {} part {} seed {}: reference says {}, solver says {}\n{}",
+            self.day,
+            self.part,
+            self.seed,
+            self.expected,
+            self.actual,
+            self.input
+        )
+    }
+}
+
+// Sizes from tiny upwards, small enough for the reference solvers to stay quick
+pub fn size_ladder() -> Vec<Params> {
+    (1..=4)
+        .map(|level| Params {
+            moves: 5 * level,
+            max_steps: 50 * level,
+            ranges: 2 * level,
+            range_width: 20 * level,
+            banks: 2 * level,
+            bank_length: 11 + level,
+            width: 3 + 2 * level,
+            height: 4 + 2 * level,
+            density: 30,
+            ids: 5 * level,
+            worksheets: level,
+            rows: level,
+            points: 5 * level,
+            connections: 3 * level,
+            max_coord: 10 * level,
+            vertices: 4 + 2 * level,
+        })
+        .collect()
+}
+
+fn run_solver(day: &Day, part: u8, input: &str) -> String {
+    match panic::catch_unwind(AssertUnwindSafe(|| (day.run)(input, &[part]))) {
+        Ok(Ok(result)) => result.parts[0].answer.clone(),
+        Ok(Err(e)) => format!("error: {}", e),
+        Err(_) => "panic".to_string(),
+    }
+}
+
+// Runs the solver against the reference for every seed at each size, smallest sizes first.
+// Stops at the first size that fails and returns its shortest failing input. A size the
+// generator rejects is an error, not a pass.
+pub fn differential(day: &Day, seeds: u64, sizes: &[Params]) -> AocResult<Option<Mismatch>> {
+    for params in sizes {
+        let mut smallest: Option<Mismatch> = None;
+        for seed in 0..seeds {
+            let input = generate::generate(day.day, seed, params)?;
+            for &part in day.parts {
+                let Some(expected) = solve(day.day, part, &input) else {
+                    continue;
+                };
+                let actual = run_solver(day, part, &input);
+                let shorter = smallest.as_ref().is_none_or(|m| input.len() < m.input.len());
+                if actual != expected && shorter {
+                    smallest = Some(Mismatch {
+                        day: day.day,
+                        part,
+                        seed,
+                        input: input.clone(),
+                        expected,
+                        actual,
+                    });
+                }
+            }
+        }
+        if smallest.is_some() {
+            return Ok(smallest);
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn references_solve_examples() {
+        for day in registry::all() {
+            let run = (day.run)(day.example, day.parts).unwrap();
+            for part in &run.parts {
+                let expected = solve(day.day, part.part, day.example);
+                assert_eq!(expected.as_ref(), Some(&part.answer), "day {}", day.day);
+            }
+        }
+    }
+
+    #[test]
+    fn solvers_match_references() {
+        for day in registry::all() {
+            if let Some(mismatch) = differential(day, 20, &size_ladder()).unwrap() {
+                panic!("{}", mismatch);
+            }
+        }
+    }
+
+    #[test]
+    fn generator_errors_are_not_a_pass() {
+        let day = registry::find(9).unwrap();
+        let odd = Params { vertices: 5, ..Params::default() };
+        let err = differential(day, 1, &[odd]).unwrap_err();
+        assert_eq!(err.to_string(), "invalid input: vertices must be even, got 5");
+    }
+}
@@ -0,0 +1,80 @@
+use crate::runner::DayRun;
+use crate::AocResult;
+
+pub struct Day {
+    pub day: u8,
+    pub title: &'static str,
+    pub example: &'static str,
+    pub parts: &'static [u8],
+    pub run: fn(&str, &[u8]) -> AocResult<DayRun>,
+    // Parses arbitrary bytes and throws the result away, see `fuzz`
+    pub fuzz: fn(&[u8]),
+}
+
+impl Day {
+    pub fn has_part(&self, part: u8) -> bool {
+        self.parts.contains(&part)
+    }
+}
+
+// Declares every day module and builds the registry table from the same list
+macro_rules! days {
+    ($($day:literal => $module:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        const REGISTRY: &[$crate::registry::Day] = &[
+            $($crate::registry::Day {
+                day: $day,
+                title: <$module::Solution as $crate::Solver>::TITLE,
+                example: <$module::Solution as $crate::Solver>::EXAMPLE,
+                parts: <$module::Solution as $crate::Solver>::PARTS,
+                run: $crate::runner::run::<$module::Solution>,
+                fuzz: $crate::fuzz::parse_bytes::<$module::Solution>,
+            }),*
+        ];
+    };
+}
+
+pub fn all() -> &'static [Day] {
+    crate::REGISTRY
+}
+
+pub fn find(day: u8) -> Option<&'static Day> {
+    all()
+        .iter()
+        .find(|d| d.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_are_sorted_and_unique() {
+        let days: Vec<u8> = all()
+            .iter()
+            .map(|d| d.day)
+            .collect();
+        let mut sorted = days.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(days, sorted);
+    }
+
+    #[test]
+    fn find_registered_day() {
+        let day = find(7).unwrap();
+        assert_eq!(day.title, "Laboratories");
+        assert!(day.has_part(1));
+        assert!(day.has_part(2));
+        assert!(find(0).is_none());
+    }
+
+    #[test]
+    fn run_through_registry() {
+        let day = find(5).unwrap();
+        let result = (day.run)("3-5\n10-14\n\n4\n11\n20\n", &[1, 2]).unwrap();
+        assert_eq!(result.parts[0].answer, "2");
+        assert_eq!(result.parts[1].answer, "8");
+    }
+}
@@ -0,0 +1,257 @@
+use std::time::Duration;
+
+use crate::answers::{ AnswerKey, AnswerStore };
+use crate::registry::Day;
+use crate::runner::{ self, INPUT_NAMES };
+
+#[derive(Debug, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Unrecorded,
+    Error,
+    Panic,
+}
+
+impl Status {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Fail => "fail",
+            Status::Unrecorded => "unrecorded",
+            Status::Error => "error",
+            Status::Panic => "panic",
+        }
+    }
+}
+
+// One line of the report, a day whose input fails to parse gets one `Error` row per part
+pub struct ReportRow {
+    pub day: u8,
+    pub title: &'static str,
+    pub part: u8,
+    pub input: String,
+    pub input_hash: String,
+    pub answer: Option<String>,
+    pub expected: Option<String>,
+    pub status: Status,
+    pub parse_elapsed: Duration,
+    pub elapsed: Duration,
+    pub error: Option<String>,
+}
+
+// 64-bit FNV-1a, enough to tell which copy of an input produced an answer
+pub fn input_hash(input: &str) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in input.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    format!("{:016x}", hash)
+}
+
+// Runs every implemented part of `days` on the example and the personal input if present,
+// comparing each answer with the store
+pub fn build(days: &[&Day], store: &AnswerStore) -> Vec<ReportRow> {
+    let mut rows = Vec::new();
+
+    for day in days {
+        for name in INPUT_NAMES {
+            let Some(input) = runner::load_input(day, name) else {
+                continue;
+            };
+            let hash = input_hash(&input);
+            let row = |part: u8| ReportRow {
+                day: day.day,
+                title: day.title,
+                part,
+                input: name.to_string(),
+                input_hash: hash.clone(),
+                answer: None,
+                expected: store.get(&AnswerKey::new(day.day, part, name)).map(|a| a.to_string()),
+                status: Status::Error,
+                parse_elapsed: Duration::ZERO,
+                elapsed: Duration::ZERO,
+                error: None,
+            };
+
+            match (day.run)(&input, day.parts) {
+                Ok(result) => {
+                    for part in result.parts {
+                        let mut row = row(part.part);
+                        row.status = match &row.expected {
+                            Some(expected) if *expected == part.answer => Status::Pass,
+                            Some(_) => Status::Fail,
+                            None => Status::Unrecorded,
+                        };
+                        row.answer = Some(part.answer);
+                        row.parse_elapsed = result.parse_elapsed;
+                        row.elapsed = part.elapsed;
+                        rows.push(row);
+                    }
+                }
+                Err(e) => {
+                    for &part in day.parts {
+                        let mut row = row(part);
+                        row.error = Some(e.to_string());
+                        rows.push(row);
+                    }
+                }
+            }
+        }
+    }
+
+    rows
+}
+
+fn json_string(value: &str) -> String {
+    let mut text = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => text.push_str("\\\""),
+            '\\' => text.push_str("\\\\"),
+            '\n' => text.push_str("\\n"),
+            '\r' => text.push_str("\\r"),
+            '\t' => text.push_str("\\t"),
+            c if (c as u32) < 0x20 => text.push_str(&format!("\\u{:04x}", c as u32)),
+            c => text.push(c),
+        }
+    }
+    text.push('"');
+    text
+}
+
+fn json_option(value: &Option<String>) -> String {
+    value.as_deref().map_or("null".to_string(), json_string)
+}
+
+// An array of flat objects, durations in nanoseconds
+pub fn to_json(rows: &[ReportRow]) -> String {
+    let objects: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            format!(
+                "  {{\"day\": {}, \"title\": {}, \"part\": {}, \"input\": {}, \"input_hash\": {}, \
+                 \"answer\": {}, \"expected\": {}, \"status\": {}, \"parse_ns\": {}, \
+                 \"part_ns\": {}, \"error\": {}}}",
+                row.day,
+                json_string(row.title),
+                row.part,
+                json_string(&row.input),
+                json_string(&row.input_hash),
+                json_option(&row.answer),
+                json_option(&row.expected),
+                json_string(row.status.as_str()),
+                row.parse_elapsed.as_nanos(),
+                row.elapsed.as_nanos(),
+                json_option(&row.error)
+            )
+        })
+        .collect();
+
+    if objects.is_empty() {
+        return "[]\n".to_string();
+    }
+    format!("[\n{}\n]\n", objects.join(",\n"))
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+pub fn to_csv(rows: &[ReportRow]) -> String {
+    let mut text = String::from(
+        "day,title,part,input,input_hash,answer,expected,status,parse_ns,part_ns,error\n"
+    );
+    for row in rows {
+        let fields = [
+            format!("{:02}", row.day),
+            csv_field(row.title),
+            row.part.to_string(),
+            csv_field(&row.input),
+            row.input_hash.clone(),
+            csv_field(row.answer.as_deref().unwrap_or("")),
+            csv_field(row.expected.as_deref().unwrap_or("")),
+            row.status.as_str().to_string(),
+            row.parse_elapsed.as_nanos().to_string(),
+            row.elapsed.as_nanos().to_string(),
+            csv_field(row.error.as_deref().unwrap_or("")),
+        ];
+        text.push_str(&fields.join(","));
+        text.push('\n');
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn fnv_hash() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+        assert_ne!(input_hash("1\n2\n"), input_hash("1\n2"));
+    }
+
+    #[test]
+    fn statuses_against_store() {
+        let day = registry::find(1).unwrap();
+        let store = AnswerStore::parse("01 1 example 3\n01 2 example 7\n").unwrap();
+        let rows: Vec<ReportRow> = build(&[day], &store)
+            .into_iter()
+            .filter(|r| r.input == "example")
+            .collect();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].status, Status::Pass);
+        assert_eq!(rows[0].answer.as_deref(), Some("3"));
+        assert_eq!(rows[1].status, Status::Fail);
+        assert_eq!(rows[1].expected.as_deref(), Some("7"));
+        assert_eq!(rows[0].input_hash, input_hash(day.example));
+
+        let rows = build(&[day], &AnswerStore::default());
+        assert!(rows.iter().all(|r| r.status == Status::Unrecorded));
+    }
+
+    fn error_row() -> ReportRow {
+        ReportRow {
+            day: 3,
+            title: "Lobby",
+            part: 1,
+            input: "input".to_string(),
+            input_hash: input_hash("x"),
+            answer: None,
+            expected: Some("1, \"2\"".to_string()),
+            status: Status::Error,
+            parse_elapsed: Duration::ZERO,
+            elapsed: Duration::from_nanos(15),
+            error: Some("bad\nline".to_string()),
+        }
+    }
+
+    #[test]
+    fn json_output() {
+        let json = to_json(&[error_row()]);
+        assert!(json.starts_with("[\n  {\"day\": 3, \"title\": \"Lobby\", \"part\": 1,"));
+        assert!(json.contains("\"answer\": null, \"expected\": \"1, \\\"2\\\"\""));
+        assert!(json.contains("\"part_ns\": 15, \"error\": \"bad\\nline\"}\n]\n"));
+        assert_eq!(to_json(&[]), "[]\n");
+    }
+
+    #[test]
+    fn csv_output() {
+        let csv = to_csv(&[error_row()]);
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next().unwrap(),
+            "day,title,part,input,input_hash,answer,expected,status,parse_ns,part_ns,error"
+        );
+        assert!(csv.contains(",\"1, \"\"2\"\"\",error,0,15,\"bad\nline\"\n"));
+    }
+}
@@ -0,0 +1,215 @@
+use std::fs;
+use std::path::Path;
+use std::time::{ Duration, Instant };
+
+use crate::answers::AnswerKey;
+use crate::memory::{ self, AllocStats };
+use crate::normalize::{ normalize, Change };
+use crate::registry::Day;
+use crate::solver::Answer;
+use crate::{ AocResult, Solver };
+
+pub struct PartResult {
+    pub part: u8,
+    pub answer: String,
+    pub elapsed: Duration,
+    // Only with the counting allocator installed, see `memory`
+    pub memory: Option<AllocStats>,
+}
+
+pub struct DayRun {
+    // What normalization had to fix in the raw input
+    pub input_changes: Vec<Change>,
+    pub parse_elapsed: Duration,
+    pub parse_memory: Option<AllocStats>,
+    pub parts: Vec<PartResult>,
+}
+
+pub fn run<S: Solver>(input: &str, parts: &[u8]) -> AocResult<DayRun> {
+    let normalized = normalize(input, S::TRIM_TRAILING);
+
+    let start = Instant::now();
+    let (parsed, parse_memory) = memory::measure(|| S::parse(&normalized.text));
+    let parse_elapsed = start.elapsed();
+    let parsed = parsed?;
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            // Formatting the answer is left out of the measurement
+            let (answer, memory) = match part {
+                1 => {
+                    let (answer, memory) = memory::measure(|| S::part_one(&parsed));
+                    (answer.into_answer(), memory)
+                }
+                _ => {
+                    let (answer, memory) = memory::measure(|| S::part_two(&parsed));
+                    (answer.into_answer(), memory)
+                }
+            };
+            Ok(PartResult {
+                part,
+                answer: answer?,
+                elapsed: start.elapsed(),
+                memory,
+            })
+        })
+        .collect::<AocResult<Vec<_>>>()?;
+
+    Ok(DayRun {
+        input_changes: normalized.changes,
+        parse_elapsed,
+        parse_memory,
+        parts,
+    })
+}
+
+pub const INPUT_NAMES: [&str; 2] = ["example", "input"];
+
+pub fn input_path(day: u8) -> String {
+    input_file(day, "input")
+}
+
+// "input" is the personal puzzle input, any other name is a suffixed variant
+pub fn input_file(day: u8, name: &str) -> String {
+    match name {
+        "input" => format!("./input/day_{:02}.txt", day),
+        _ => format!("./input/day_{:02}_{}.txt", day, name),
+    }
+}
+
+// Empty files are placeholders left by `new-day` and count as missing
+pub fn has_input<P: AsRef<Path>>(path: P) -> bool {
+    fs::metadata(path).is_ok_and(|meta| meta.len() > 0)
+}
+
+// The embedded example for "example", otherwise the file on disk if it has any content
+pub fn load_input(day: &Day, name: &str) -> Option<String> {
+    match name {
+        "example" => Some(day.example.to_string()),
+        _ => {
+            let path = input_file(day.day, name);
+            has_input(&path).then(|| fs::read_to_string(path).ok()).flatten()
+        }
+    }
+}
+
+// Runs every implemented part of the given days on the embedded example and each input
+// file present on disk,
+// inputs that fail to parse are reported as errors instead of answers
+pub fn collect_answers(days: &[&Day]) -> (Vec<(AnswerKey, String)>, Vec<String>) {
+    let mut answers = Vec::new();
+    let mut errors = Vec::new();
+
+    for day in days {
+        for name in INPUT_NAMES {
+            let Some(input) = load_input(day, name) else {
+                continue;
+            };
+
+            match (day.run)(&input, day.parts) {
+                Ok(result) => {
+                    for part in result.parts {
+                        answers.push((AnswerKey::new(day.day, part.part, name), part.answer));
+                    }
+                }
+                Err(e) => errors.push(format!("day {:02} ({}): {}", day.day, name, e)),
+            }
+        }
+    }
+
+    (answers, errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AocError;
+
+    struct Echo;
+
+    impl Solver for Echo {
+        type Input = Vec<i32>;
+        type Answer1 = i32;
+        type Answer2 = AocResult<usize>;
+
+        const TITLE: &'static str = "Echo";
+        const EXAMPLE: &'static str = "1\n2\n3\n";
+
+        fn parse(input: &str) -> AocResult<Vec<i32>> {
+            input
+                .lines()
+                .enumerate()
+                .map(|(idx, l)| l.parse().map_err(|_| AocError::parse(idx + 1, 1, "not a number")))
+                .collect()
+        }
+
+        fn part_one(input: &Vec<i32>) -> i32 {
+            input.iter().sum()
+        }
+
+        fn part_two(input: &Vec<i32>) -> AocResult<usize> {
+            if input.is_empty() {
+                return Err(AocError::invalid("nothing to count"));
+            }
+            Ok(input.len())
+        }
+    }
+
+    #[test]
+    fn run_selected_parts() {
+        let result = run::<Echo>("1\n2\n3\n", &[1, 2]).unwrap();
+        assert_eq!(result.parts.len(), 2);
+        assert_eq!(result.parts[0].answer, "6");
+        assert_eq!(result.parts[1].answer, "3");
+
+        let result = run::<Echo>("1\n2\n3\n", &[2]).unwrap();
+        assert_eq!(result.parts.len(), 1);
+        assert_eq!(result.parts[0].part, 2);
+    }
+
+    #[test]
+    fn run_normalizes_input() {
+        let result = run::<Echo>("\u{feff}1\r\n2 \r\n", &[1]).unwrap();
+        assert_eq!(result.parts[0].answer, "3");
+        assert_eq!(result.input_changes.len(), 3);
+    }
+
+    #[test]
+    fn run_measures_allocations() {
+        let result = run::<Echo>("1\n2\n", &[1]).unwrap();
+        assert!(result.parse_memory.unwrap().allocations > 0);
+        assert!(result.parts[0].memory.is_some());
+    }
+
+    #[test]
+    fn run_reports_parse_error() {
+        let err = run::<Echo>("1\nx\n", &[1]).err().unwrap();
+        assert_eq!(err.to_string(), "parse error at line 2, column 1: not a number");
+    }
+
+    #[test]
+    fn run_reports_part_error() {
+        assert_eq!(run::<Echo>("", &[1]).unwrap().parts[0].answer, "0");
+        let err = run::<Echo>("", &[1, 2]).err().unwrap();
+        assert_eq!(err.to_string(), "invalid input: nothing to count");
+    }
+
+    #[test]
+    fn empty_input_counts_as_missing() {
+        let path = std::env::temp_dir().join(format!("aoc2025_input_{}", std::process::id()));
+        fs::write(&path, "").unwrap();
+        assert!(!has_input(&path));
+        fs::write(&path, "1\n").unwrap();
+        assert!(has_input(&path));
+        fs::remove_file(&path).unwrap();
+        assert!(!has_input(&path));
+    }
+
+    #[test]
+    fn default_input_path() {
+        assert_eq!(input_path(7), "./input/day_07.txt");
+        assert_eq!(input_file(7, "example"), "./input/day_07_example.txt");
+    }
+}
@@ -0,0 +1,133 @@
+use std::fs;
+use std::path::{ Path, PathBuf };
+
+use crate::{ AocError, AocResult };
+
+const TEMPLATE: &str = include_str!("../template.rs");
+// Zero padded for file and module names, plain where the day is a number literal
+const PLACEHOLDER: &str = "{{DAY}}";
+const NUMBER_PLACEHOLDER: &str = "{{DAY_NUMBER}}";
+
+pub fn render_template(day: u8) -> String {
+    TEMPLATE
+        .replace(PLACEHOLDER, &format!("{:02}", day))
+        .replace(NUMBER_PLACEHOLDER, &day.to_string())
+}
+
+// Adds `N => day_NN,` to the `days!` list in lib.rs, keeping it sorted by day
+pub fn register_day(lib_rs: &str, day: u8) -> AocResult<String> {
+    let module = format!("day_{:02}", day);
+    let entry = format!("    {} => {},", day, module);
+
+    let mut lines: Vec<&str> = lib_rs.lines().collect();
+    let start = lines
+        .iter()
+        .position(|l| l.trim() == "days! {")
+        .ok_or(AocError::invalid("lib.rs has no `days! {` block"))?;
+    let end = lines[start..]
+        .iter()
+        .position(|l| l.trim() == "}")
+        .map(|offset| start + offset)
+        .ok_or(AocError::invalid("`days!` block in lib.rs is not closed"))?;
+
+    let mut insert_at = end;
+    for (idx, line) in lines.iter().enumerate().take(end).skip(start + 1) {
+        let Some((registered, _)) = line.trim().split_once("=>") else {
+            continue;
+        };
+        let registered: u8 = registered.trim().parse().map_err(|_| {
+            AocError::invalid_at(idx + 1, format!("unexpected entry `{}`", line.trim()))
+        })?;
+        if registered == day {
+            return Err(AocError::invalid(format!("day {} is already registered", day)));
+        }
+        if registered > day {
+            insert_at = insert_at.min(idx);
+        }
+    }
+
+    lines.insert(insert_at, &entry);
+    let mut text = lines.join("\n");
+    text.push('\n');
+    Ok(text)
+}
+
+// Creates the module, registers it and adds placeholder input files under `root`,
+// returns every file that was written
+pub fn create_day(root: &Path, day: u8) -> AocResult<Vec<PathBuf>> {
+    let module_path = root.join(format!("src/day_{:02}.rs", day));
+    if module_path.exists() {
+        return Err(AocError::invalid(format!("{} already exists", module_path.display())));
+    }
+
+    let lib_path = root.join("src/lib.rs");
+    let lib_rs = register_day(&fs::read_to_string(&lib_path)?, day)?;
+
+    fs::create_dir_all(root.join("input"))?;
+    fs::write(&module_path, render_template(day))?;
+    fs::write(&lib_path, lib_rs)?;
+    let mut written = vec![module_path, lib_path];
+
+    for name in ["example", "input"] {
+        let path = root.join(crate::runner::input_file(day, name).trim_start_matches("./"));
+        if !path.exists() {
+            fs::write(&path, "")?;
+            written.push(path);
+        }
+    }
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB_RS: &str = "pub mod solver;\n\ndays! {\n    1 => day_01,\n    3 => day_03,\n}\n";
+
+    #[test]
+    fn render_fills_day_number() {
+        let source = render_template(4);
+        assert!(source.contains("include_str!(\"../input/day_04_example.txt\")"));
+        assert!(source.contains("require_input!(\"./input/day_04.txt\")"));
+        assert!(source.contains("answers::assert_recorded(4, 2, Solution::part_two(&input))"));
+        assert!(!source.contains(PLACEHOLDER));
+        assert!(!source.contains(NUMBER_PLACEHOLDER));
+    }
+
+    #[test]
+    fn register_keeps_days_sorted() {
+        let lib_rs = register_day(LIB_RS, 2).unwrap();
+        assert_eq!(
+            lib_rs,
+            "pub mod solver;\n\ndays! {\n    1 => day_01,\n    2 => day_02,\n    3 => day_03,\n}\n"
+        );
+
+        let lib_rs = register_day(LIB_RS, 10).unwrap();
+        assert!(lib_rs.contains("    3 => day_03,\n    10 => day_10,\n}"));
+    }
+
+    #[test]
+    fn register_refuses_existing_day() {
+        assert!(register_day(LIB_RS, 3).is_err());
+        assert!(register_day("pub mod solver;\n", 3).is_err());
+    }
+
+    #[test]
+    fn create_day_refuses_to_overwrite() {
+        let root = std::env::temp_dir().join(format!("aoc2025_scaffold_{}", std::process::id()));
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join("src/lib.rs"), LIB_RS).unwrap();
+
+        let written = create_day(&root, 2).unwrap();
+        assert_eq!(written.len(), 4);
+        assert!(root.join("input/day_02_example.txt").exists());
+        assert!(fs::read_to_string(root.join("src/lib.rs")).unwrap().contains("2 => day_02"));
+
+        fs::write(root.join("src/day_03.rs"), "// solved\n").unwrap();
+        assert!(create_day(&root, 3).is_err());
+        assert_eq!(fs::read_to_string(root.join("src/day_03.rs")).unwrap(), "// solved\n");
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
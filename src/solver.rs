@@ -0,0 +1,84 @@
+use std::fs::File;
+use std::io::{ BufRead, BufReader };
+use std::path::Path;
+
+use crate::normalize::normalize;
+use crate::AocResult;
+
+// What a part returns: a plain value, or an error for inputs that parse but have no answer
+pub trait Answer {
+    fn into_answer(self) -> AocResult<String>;
+}
+
+macro_rules! answer {
+    ($($t:ty),*) => {
+        $(impl Answer for $t {
+            fn into_answer(self) -> AocResult<String> {
+                Ok(self.to_string())
+            }
+        })*
+    };
+}
+
+answer!(i32, i64, u32, u64, usize, String);
+
+impl<T: Answer> Answer for AocResult<T> {
+    fn into_answer(self) -> AocResult<String> {
+        self?.into_answer()
+    }
+}
+
+pub trait Solver {
+    type Input;
+    type Answer1: Answer;
+    type Answer2: Answer;
+
+    const TITLE: &'static str;
+    // Example input from the puzzle text, embedded so tests run from a fresh clone
+    const EXAMPLE: &'static str;
+    // Parts that are solved and safe to run
+    const PARTS: &'static [u8] = &[1, 2];
+    // Whether input loaders may trim trailing whitespace, off for column-aligned inputs
+    const TRIM_TRAILING: bool = true;
+
+    fn parse(input: &str) -> AocResult<Self::Input>;
+    fn part_one(input: &Self::Input) -> Self::Answer1;
+    fn part_two(input: &Self::Input) -> Self::Answer2;
+
+    fn parse_reader<R: BufRead>(mut reader: R) -> AocResult<Self::Input> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        Self::parse(&normalize(&input, Self::TRIM_TRAILING).text)
+    }
+
+    fn parse_file<P: AsRef<Path>>(path: P) -> AocResult<Self::Input> {
+        Self::parse_reader(BufReader::new(File::open(path)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_05;
+    use crate::AocError;
+
+    #[test]
+    fn parse_from_reader() {
+        let reader: &[u8] = b"3-5\n10-14\n\n4\n11\n20\n";
+        let input = day_05::Solution::parse_reader(reader).unwrap();
+        assert_eq!(day_05::Solution::part_one(&input), 2);
+    }
+
+    #[test]
+    fn parse_reader_normalizes() {
+        let reader: &[u8] = b"\xef\xbb\xbf3-5\r\n10-14 \r\n\r\n4\r\n11\r\n20\r\n";
+        let input = day_05::Solution::parse_reader(reader).unwrap();
+        assert_eq!(day_05::Solution::part_one(&input), 2);
+    }
+
+    #[test]
+    fn parse_file_reports_io_error() {
+        let err = day_05::Solution::parse_file("./input/does_not_exist.txt").err().unwrap();
+        assert!(matches!(err, AocError::Io(_)));
+    }
+}
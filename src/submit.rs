@@ -0,0 +1,311 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::error::column_of;
+use crate::fetch::Client;
+use crate::http::Request;
+use crate::parse;
+use crate::{ AocError, AocResult };
+
+pub const WRONG_ANSWERS_PATH: &str = "./wrong_answers.txt";
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Correct,
+    Wrong(Option<Hint>),
+    AlreadySolved,
+    RateLimited(Option<Duration>),
+    Unknown(String),
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Wrong(None) => write!(f, "wrong"),
+            Verdict::Wrong(Some(Hint::TooHigh)) => write!(f, "wrong, too high"),
+            Verdict::Wrong(Some(Hint::TooLow)) => write!(f, "wrong, too low"),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+            Verdict::RateLimited(Some(wait)) => {
+                write!(f, "rate limited, wait {}s", wait.as_secs())
+            }
+            Verdict::RateLimited(None) => write!(f, "rate limited"),
+            Verdict::Unknown(text) => write!(f, "unrecognised response: {}", text),
+        }
+    }
+}
+
+// Reads the verdict out of the `<article>` of the answer page
+pub fn parse_verdict(html: &str) -> Verdict {
+    let text = strip_tags(article(html));
+
+    if text.contains("That's the right answer") {
+        Verdict::Correct
+    } else if text.contains("That's not the right answer") {
+        let hint = if text.contains("too high") {
+            Some(Hint::TooHigh)
+        } else if text.contains("too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+        Verdict::Wrong(hint)
+    } else if text.contains("You gave an answer too recently") {
+        Verdict::RateLimited(parse_wait(&text))
+    } else if text.contains("Did you already complete it") {
+        Verdict::AlreadySolved
+    } else {
+        let summary: String = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        Verdict::Unknown(summary.chars().take(120).collect())
+    }
+}
+
+fn article(html: &str) -> &str {
+    let start = html.find("<article").unwrap_or(0);
+    let end = html[start..]
+        .find("</article>")
+        .map(|idx| start + idx)
+        .unwrap_or(html.len());
+    &html[start..end]
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => {
+                in_tag = true;
+            }
+            '>' => {
+                in_tag = false;
+            }
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+// "You have 1m 5s left to wait." or "You have 38s left to wait."
+fn parse_wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+
+    let mut seconds = 0;
+    for token in text[start..end].split_whitespace() {
+        let (value, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+        let value: u64 = value.parse().ok()?;
+        seconds += match unit {
+            "h" => value * 3600,
+            "m" => value * 60,
+            "s" => value,
+            _ => {
+                return None;
+            }
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+pub fn submit_answer(client: &Client, day: u8, part: u8, answer: &str) -> AocResult<Verdict> {
+    let url = format!("{}/answer", client.day_url(day));
+    let level = part.to_string();
+    let response = client.send(Request::post_form(&url, &[("level", &level), ("answer", answer)]))?;
+    Ok(parse_verdict(&response.body))
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct WrongAnswer {
+    pub answer: String,
+    pub hint: Option<Hint>,
+}
+
+// Every rejected answer per (day, part), so the same value is never sent twice
+#[derive(Default)]
+pub struct WrongAnswers {
+    answers: BTreeMap<(u8, u8), Vec<WrongAnswer>>,
+}
+
+impl WrongAnswers {
+    pub fn parse(text: &str) -> AocResult<Self> {
+        let mut wrong = WrongAnswers::default();
+
+        for (idx, raw) in text.lines().enumerate() {
+            let line = raw.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() != 4 {
+                let message = format!("expected `<day> <part> <hint> <answer>`, got `{}`", line);
+                return Err(AocError::parse(1, column_of(raw, line), message).at_line(idx + 1));
+            }
+
+            let day = parse::value(raw, fields[0], "day").map_err(|e| e.at_line(idx + 1))?;
+            let part = parse::value(raw, fields[1], "part").map_err(|e| e.at_line(idx + 1))?;
+            let hint = match fields[2] {
+                "high" => Some(Hint::TooHigh),
+                "low" => Some(Hint::TooLow),
+                "-" => None,
+                other => {
+                    let message = format!("invalid hint `{}`", other);
+                    return Err(
+                        AocError::parse(1, column_of(raw, other), message).at_line(idx + 1)
+                    );
+                }
+            };
+            wrong.insert(day, part, fields[3], hint);
+        }
+
+        Ok(wrong)
+    }
+
+    pub fn load(path: &str) -> AocResult<Self> {
+        if !Path::new(path).exists() {
+            return Ok(WrongAnswers::default());
+        }
+
+        WrongAnswers::parse(&fs::read_to_string(path)?)
+    }
+
+    pub fn save(&self, path: &str) -> io::Result<()> {
+        fs::write(path, self.to_text())
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = String::from("# day part hint answer\n");
+        for ((day, part), answers) in &self.answers {
+            for wrong in answers {
+                let hint = match wrong.hint {
+                    Some(Hint::TooHigh) => "high",
+                    Some(Hint::TooLow) => "low",
+                    None => "-",
+                };
+                text.push_str(&format!("{:02} {} {} {}\n", day, part, hint, wrong.answer));
+            }
+        }
+        text
+    }
+
+    pub fn insert(&mut self, day: u8, part: u8, answer: &str, hint: Option<Hint>) {
+        let answers = self.answers.entry((day, part)).or_default();
+        if !answers.iter().any(|w| w.answer == answer) {
+            answers.push(WrongAnswer { answer: answer.to_string(), hint });
+        }
+    }
+
+    // Finds an earlier guess that already rules `answer` out, either the same value or a
+    // numeric bound it lies beyond
+    pub fn rules_out(&self, day: u8, part: u8, answer: &str) -> Option<&WrongAnswer> {
+        let answers = self.answers.get(&(day, part))?;
+        let value = answer.parse::<i128>().ok();
+
+        answers.iter().find(|wrong| {
+            if wrong.answer == answer {
+                return true;
+            }
+            match (value, wrong.answer.parse::<i128>().ok(), wrong.hint) {
+                (Some(value), Some(bound), Some(Hint::TooHigh)) => value >= bound,
+                (Some(value), Some(bound), Some(Hint::TooLow)) => value <= bound,
+                _ => false,
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::tests::{ ok_response, stand_in_server };
+
+    fn page(message: &str) -> String {
+        format!(
+            "<html><body><main><article><p>{}</p></article></main></body></html>",
+            message
+        )
+    }
+
+    #[test]
+    fn verdicts_from_pages() {
+        let correct = page("That's the right answer!  You are <em>one gold star</em> closer.");
+        assert_eq!(parse_verdict(&correct), Verdict::Correct);
+
+        let high = page("That's not the right answer; your answer is too high.  Please wait.");
+        assert_eq!(parse_verdict(&high), Verdict::Wrong(Some(Hint::TooHigh)));
+
+        let low = page("That's not the right answer; your answer is too low.");
+        assert_eq!(parse_verdict(&low), Verdict::Wrong(Some(Hint::TooLow)));
+
+        let wrong = page("That's not the right answer.  If you're stuck, ask for help.");
+        assert_eq!(parse_verdict(&wrong), Verdict::Wrong(None));
+
+        let solved = page(
+            "You don't seem to be solving the right level.  Did you already complete it?"
+        );
+        assert_eq!(parse_verdict(&solved), Verdict::AlreadySolved);
+
+        let limited = page("You gave an answer too recently.  You have 1m 5s left to wait.");
+        assert_eq!(parse_verdict(&limited), Verdict::RateLimited(Some(Duration::from_secs(65))));
+
+        let limited = page("You gave an answer too recently.  You have 38s left to wait.");
+        assert_eq!(parse_verdict(&limited), Verdict::RateLimited(Some(Duration::from_secs(38))));
+
+        assert!(matches!(parse_verdict("<html>Log in</html>"), Verdict::Unknown(_)));
+    }
+
+    #[test]
+    fn wrong_answers_round_trip() {
+        let mut wrong = WrongAnswers::default();
+        wrong.insert(3, 1, "100", Some(Hint::TooHigh));
+        wrong.insert(3, 1, "40", Some(Hint::TooLow));
+        wrong.insert(3, 2, "abc", None);
+        wrong.insert(3, 2, "abc", None);
+
+        let text = wrong.to_text();
+        assert_eq!(text, "# day part hint answer\n03 1 high 100\n03 1 low 40\n03 2 - abc\n");
+        assert_eq!(WrongAnswers::parse(&text).unwrap().to_text(), text);
+        let err = WrongAnswers::parse("03 1 maybe 5\n").err().unwrap();
+        assert_eq!(err.to_string(), "parse error at line 1, column 6: invalid hint `maybe`");
+    }
+
+    #[test]
+    fn rules_out_repeats_and_bounds() {
+        let mut wrong = WrongAnswers::default();
+        wrong.insert(3, 1, "100", Some(Hint::TooHigh));
+        wrong.insert(3, 1, "40", Some(Hint::TooLow));
+        wrong.insert(3, 2, "77", None);
+
+        assert_eq!(wrong.rules_out(3, 1, "120").unwrap().answer, "100");
+        assert_eq!(wrong.rules_out(3, 1, "40").unwrap().answer, "40");
+        assert!(wrong.rules_out(3, 1, "70").is_none());
+        assert!(wrong.rules_out(3, 2, "77").is_some());
+        assert!(wrong.rules_out(3, 2, "78").is_none());
+        assert!(wrong.rules_out(4, 1, "100").is_none());
+    }
+
+    #[test]
+    fn posts_level_and_answer() {
+        let body = page("That's the right answer!");
+        let (base_url, server) = stand_in_server(vec![ok_response(&body)]);
+        let client = Client::new(&base_url, "abc");
+
+        assert_eq!(submit_answer(&client, 7, 2, "40").unwrap(), Verdict::Correct);
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2025/day/7/answer HTTP/1.1\r\n"));
+        assert!(requests[0].contains("Cookie: session=abc\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=2&answer=40"));
+    }
+}
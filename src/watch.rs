@@ -0,0 +1,204 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{ Path, PathBuf };
+use std::process::Command;
+use std::thread;
+use std::time::{ Duration, SystemTime };
+
+use crate::runner::{ self, INPUT_NAMES };
+
+pub const DEFAULT_INTERVAL: Duration = Duration::from_millis(500);
+
+// Keyed by (part, input name), holds the answer or the error text
+pub type Answers = BTreeMap<(u8, String), String>;
+
+// lib.rs is included so a freshly registered day is picked up as well
+pub fn watched_paths(root: &Path, day: u8) -> Vec<PathBuf> {
+    let mut paths = vec![root.join(format!("src/day_{:02}.rs", day)), root.join("src/lib.rs")];
+    for name in INPUT_NAMES {
+        paths.push(root.join(runner::input_file(day, name).trim_start_matches("./")));
+    }
+    paths
+}
+
+// Modification times, None for files that do not exist (yet)
+pub fn snapshot(paths: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    paths
+        .iter()
+        .map(|path| fs::metadata(path).and_then(|meta| meta.modified()).ok())
+        .collect()
+}
+
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                fields.last_mut().unwrap().push('"');
+            }
+            '"' => {
+                quoted = !quoted;
+            }
+            ',' if !quoted => fields.push(String::new()),
+            c => fields.last_mut().unwrap().push(c),
+        }
+    }
+    fields
+}
+
+// Reads the answers back out of `report --format csv`
+pub fn answers_from_csv(csv: &str) -> Answers {
+    let mut answers = Answers::new();
+    let mut lines = csv.lines();
+    let Some(header) = lines.next() else {
+        return answers;
+    };
+    let header = split_csv_line(header);
+    let column = |name: &str| header.iter().position(|h| h == name);
+    let (Some(part), Some(input), Some(answer), Some(error)) = (
+        column("part"),
+        column("input"),
+        column("answer"),
+        column("error"),
+    ) else {
+        return answers;
+    };
+
+    for line in lines {
+        let fields = split_csv_line(line);
+        let Some(part_number) = fields.get(part).and_then(|p| p.parse::<u8>().ok()) else {
+            continue;
+        };
+        let value = match fields.get(error) {
+            Some(e) if !e.is_empty() => format!("error: {}", e),
+            _ => fields.get(answer).cloned().unwrap_or_default(),
+        };
+        answers.insert((part_number, fields.get(input).cloned().unwrap_or_default()), value);
+    }
+    answers
+}
+
+// One line per answer, marking what changed since the previous run
+pub fn diff(previous: &Answers, current: &Answers) -> Vec<String> {
+    current
+        .iter()
+        .map(|((part, input), answer)| {
+            let label = format!("part {} ({})", part, input);
+            match previous.get(&(*part, input.clone())) {
+                None => format!("  {}: {}", label, answer),
+                Some(before) if before == answer => format!("  {}: {} (unchanged)", label, answer),
+                Some(before) => format!("  {}: {} -> {}", label, before, answer),
+            }
+        })
+        .collect()
+}
+
+// Rebuilds and runs the day in a child cargo so source edits take effect
+fn run_day(root: &Path, day: u8) -> Result<Answers, String> {
+    let cargo = env::var("CARGO").unwrap_or("cargo".to_string());
+    let output = Command::new(cargo)
+        .current_dir(root)
+        .args(["run", "--quiet", "--", "report", "--format", "csv", "--day"])
+        .arg(day.to_string())
+        .output()
+        .map_err(|e| format!("cannot start cargo: {}", e))?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).to_string());
+    }
+    Ok(answers_from_csv(&String::from_utf8_lossy(&output.stdout)))
+}
+
+pub enum Event {
+    Running,
+    // Lines describing the answers that changed since the previous run
+    Finished(Vec<String>),
+    Failed(String),
+}
+
+// Polls until interrupted, re-running the day whenever one of its files changes.
+// Progress goes to `on_event`, the caller decides how to show it.
+pub fn watch_day<F>(root: &Path, day: u8, interval: Duration, mut on_event: F) -> !
+    where F: FnMut(Event)
+{
+    let paths = watched_paths(root, day);
+    let mut seen: Option<Vec<Option<SystemTime>>> = None;
+    let mut previous = Answers::new();
+
+    loop {
+        let current = snapshot(&paths);
+        if seen.as_ref() != Some(&current) {
+            seen = Some(current);
+            on_event(Event::Running);
+            match run_day(root, day) {
+                Ok(answers) => {
+                    on_event(Event::Finished(diff(&previous, &answers)));
+                    previous = answers;
+                }
+                Err(message) => on_event(Event::Failed(message)),
+            }
+        }
+        thread::sleep(interval);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snapshot_sees_new_and_changed_files() {
+        let root = env::temp_dir().join(format!("aoc2025_watch_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src")).unwrap();
+        let paths = watched_paths(&root, 4);
+        assert_eq!(paths.len(), 4);
+
+        let empty = snapshot(&paths);
+        assert!(empty.iter().all(|t| t.is_none()));
+
+        fs::write(root.join("src/day_04.rs"), "// v1\n").unwrap();
+        let first = snapshot(&paths);
+        assert_ne!(first, empty);
+
+        let file = fs::File::options().write(true).open(root.join("src/day_04.rs")).unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(5)).unwrap();
+        assert_ne!(snapshot(&paths), first);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn reads_report_csv() {
+        let csv = "day,title,part,input,input_hash,answer,expected,status,parse_ns,part_ns,error\n\
+                   04,Printing,1,example,ab,13,13,pass,1,2,\n\
+                   04,Printing,1,input,cd,,,error,0,0,\"parse error, \"\"x\"\"\"\n";
+        let answers = answers_from_csv(csv);
+        assert_eq!(answers[&(1, "example".to_string())], "13");
+        assert_eq!(answers[&(1, "input".to_string())], "error: parse error, \"x\"");
+        assert!(answers_from_csv("").is_empty());
+    }
+
+    #[test]
+    fn diff_marks_changes() {
+        let mut previous = Answers::new();
+        previous.insert((1, "example".to_string()), "13".to_string());
+        previous.insert((2, "example".to_string()), "40".to_string());
+        let mut current = previous.clone();
+        current.insert((2, "example".to_string()), "43".to_string());
+        current.insert((1, "input".to_string()), "1428".to_string());
+
+        assert_eq!(
+            diff(&previous, &current),
+            vec![
+                "  part 1 (example): 13 (unchanged)",
+                "  part 1 (input): 1428",
+                "  part 2 (example): 40 -> 43"
+            ]
+        );
+    }
+}
@@ -1,20 +1,74 @@
-// struct Solution;
-
-// #[allow(dead_code)]
-// impl Solution {
-//     pub fn solve() {
-//         // Implement solution for problem 2 here
-//     }
-// }
-
-// #[cfg(test)]
-// mod tests {
-//     use super::Solution;
-
-//     #[test]
-//     fn test_case_1() {
-//         let input = 
-//         let output =
-//         assert_eq!(Solution::solve(input), output);
-//     }
-// }
\ No newline at end of file
+use crate::{ AocError, AocResult, Solver };
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<String>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    const TITLE: &'static str = "Day {{DAY}}";
+    const EXAMPLE: &'static str = include_str!("../input/day_{{DAY}}_example.txt");
+    // Add parts here once they are solved so runners pick them up
+    const PARTS: &'static [u8] = &[];
+
+    fn parse(input: &str) -> AocResult<Vec<String>> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| {
+                if line.is_empty() {
+                    return Err(AocError::invalid_at(idx + 1, "unexpected empty line"));
+                }
+                Ok(line.to_string())
+            })
+            .collect()
+    }
+
+    fn part_one(_input: &Vec<String>) -> i64 {
+        todo!()
+    }
+
+    fn part_two(_input: &Vec<String>) -> i64 {
+        todo!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers;
+
+    // Replace with the answers given in the puzzle text
+    fn expected_example(part: u8) -> i64 {
+        todo!("example answer for part {}", part)
+    }
+
+    #[test]
+    fn part_one_example() {
+        let input = Solution::parse(Solution::EXAMPLE).unwrap();
+        assert_eq!(Solution::part_one(&input), expected_example(1));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Solution::parse(Solution::EXAMPLE).unwrap();
+        assert_eq!(Solution::part_two(&input), expected_example(2));
+    }
+
+    #[test]
+    fn part_one() {
+        require_input!("./input/day_{{DAY}}.txt");
+
+        let input = Solution::parse_file("./input/day_{{DAY}}.txt").unwrap();
+        answers::assert_recorded({{DAY_NUMBER}}, 1, Solution::part_one(&input));
+    }
+
+    #[test]
+    fn part_two() {
+        require_input!("./input/day_{{DAY}}.txt");
+
+        let input = Solution::parse_file("./input/day_{{DAY}}.txt").unwrap();
+        answers::assert_recorded({{DAY_NUMBER}}, 2, Solution::part_two(&input));
+    }
+}